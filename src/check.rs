@@ -1,53 +1,346 @@
 use itertools::Itertools;
 use regex;
-use std::{error::Error, fmt::Display};
+use std::{
+    cell::RefCell,
+    collections::{HashMap, HashSet},
+    error::Error,
+    fmt::Display,
+    sync::OnceLock,
+};
 
-use crate::value::{Value, ValueKind};
+#[cfg(feature = "config")]
+use crate::config::RuleSpec;
+use crate::{
+    iso,
+    value::{KindSet, Value, ValueKind},
+};
 
 pub trait Checker {
     fn check(&self, value: &Value) -> Result<Notice, CheckError>;
-    fn expecting(&self) -> Vec<ValueKind>;
+    fn expecting(&self) -> KindSet;
+
+    fn check_with_context(
+        &self,
+        value: &Value,
+        _context: &HashMap<String, Value>,
+    ) -> Result<Notice, CheckError> {
+        self.check(value)
+    }
+
+    fn describe(&self) -> String {
+        "custom check".to_string()
+    }
+
+    fn labels(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Relative importance of the key in `CheckList::score`.
+    fn weight(&self) -> f64 {
+        1.0
+    }
+
+    /// Messages this checker can report, for documentation and fixtures.
+    fn messages(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Whether `check_with_context` reads the context. Checkers that override it to
+    /// look at other fields should return `true` so `Memoized` doesn't cache them.
+    fn uses_context(&self) -> bool {
+        false
+    }
+
+    /// This checker as a config rule, when the loader has a matching rule type.
+    #[cfg(feature = "config")]
+    fn spec(&self) -> Option<RuleSpec> {
+        None
+    }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+impl<T> Checker for Box<T>
+where
+    T: Checker + ?Sized,
+{
+    fn check(&self, value: &Value) -> Result<Notice, CheckError> {
+        (**self).check(value)
+    }
+
+    fn check_with_context(
+        &self,
+        value: &Value,
+        context: &HashMap<String, Value>,
+    ) -> Result<Notice, CheckError> {
+        (**self).check_with_context(value, context)
+    }
+
+    fn expecting(&self) -> KindSet {
+        (**self).expecting()
+    }
+
+    fn describe(&self) -> String {
+        (**self).describe()
+    }
+
+    fn labels(&self) -> Vec<String> {
+        (**self).labels()
+    }
+
+    fn weight(&self) -> f64 {
+        (**self).weight()
+    }
+
+    fn messages(&self) -> Vec<String> {
+        (**self).messages()
+    }
+
+    fn uses_context(&self) -> bool {
+        (**self).uses_context()
+    }
+
+    #[cfg(feature = "config")]
+    fn spec(&self) -> Option<RuleSpec> {
+        (**self).spec()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(into = "NoticeRepr", try_from = "NoticeRepr"))]
 pub enum Notice {
     Clear,
+    Info(String),
     Attention(String),
     Error(String),
+    AttentionCoded { code: String, message: String },
+    ErrorCoded { code: String, message: String },
+}
+
+/// Wire form of `Notice`: coded notices share their level's `type` and add a `code`.
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+struct NoticeRepr {
+    #[serde(rename = "type")]
+    level: NoticeType,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+}
+
+#[cfg(feature = "serde")]
+#[derive(serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "lowercase")]
+enum NoticeType {
+    Clear,
+    Info,
+    Attention,
+    Error,
+}
+
+#[cfg(feature = "serde")]
+impl From<Notice> for NoticeRepr {
+    fn from(value: Notice) -> Self {
+        let (level, code, message) = match value {
+            Notice::Clear => (NoticeType::Clear, None, None),
+            Notice::Info(msg) => (NoticeType::Info, None, Some(msg)),
+            Notice::Attention(msg) => (NoticeType::Attention, None, Some(msg)),
+            Notice::Error(msg) => (NoticeType::Error, None, Some(msg)),
+            Notice::AttentionCoded { code, message } => {
+                (NoticeType::Attention, Some(code), Some(message))
+            }
+            Notice::ErrorCoded { code, message } => (NoticeType::Error, Some(code), Some(message)),
+        };
+        NoticeRepr {
+            level,
+            code,
+            message,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<NoticeRepr> for Notice {
+    type Error = String;
+
+    fn try_from(value: NoticeRepr) -> Result<Self, String> {
+        let message = || value.message.clone().ok_or("missing field `message`");
+        Ok(match (value.level, value.code.clone()) {
+            (NoticeType::Clear, None) => Notice::Clear,
+            (NoticeType::Info, None) => Notice::Info(message()?),
+            (NoticeType::Attention, None) => Notice::Attention(message()?),
+            (NoticeType::Error, None) => Notice::Error(message()?),
+            (NoticeType::Attention, Some(code)) => Notice::AttentionCoded {
+                code,
+                message: message()?,
+            },
+            (NoticeType::Error, Some(code)) => Notice::ErrorCoded {
+                code,
+                message: message()?,
+            },
+            (NoticeType::Clear | NoticeType::Info, Some(_)) => {
+                return Err("only attention and error notices take a `code`".to_string())
+            }
+        })
+    }
+}
+
+/// A `Notice::severity` threshold, from any non-clear notice up to errors only.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info = 1,
+    Attention = 2,
+    Error = 3,
 }
 
 impl From<Notice> for Result<Notice, String> {
     fn from(value: Notice) -> Self {
         match value {
             Notice::Clear => Ok(Notice::Clear),
+            Notice::Info(msg) => Ok(Notice::Info(msg)),
             Notice::Attention(msg) => Ok(Notice::Attention(msg)),
             Notice::Error(msg) => Err(msg),
+            Notice::AttentionCoded { code, message } => {
+                Ok(Notice::AttentionCoded { code, message })
+            }
+            Notice::ErrorCoded { message, .. } => Err(message),
+        }
+    }
+}
+
+impl Notice {
+    fn with_message(self, msg: &str) -> Notice {
+        self.map_message(|_| msg.to_string())
+    }
+
+    pub(crate) fn map_message<F>(self, f: F) -> Notice
+    where
+        F: FnOnce(String) -> String,
+    {
+        match self {
+            Notice::Clear => Notice::Clear,
+            Notice::Info(msg) => Notice::Info(f(msg)),
+            Notice::Attention(msg) => Notice::Attention(f(msg)),
+            Notice::Error(msg) => Notice::Error(f(msg)),
+            Notice::AttentionCoded { code, message } => Notice::AttentionCoded {
+                code,
+                message: f(message),
+            },
+            Notice::ErrorCoded { code, message } => Notice::ErrorCoded {
+                code,
+                message: f(message),
+            },
+        }
+    }
+
+    /// Attaches a machine-readable code to an attention or error notice. Clear and
+    /// info notices are returned unchanged.
+    pub fn with_code(self, code: impl Into<String>) -> Notice {
+        match self {
+            Notice::Attention(message) | Notice::AttentionCoded { message, .. } => {
+                Notice::AttentionCoded {
+                    code: code.into(),
+                    message,
+                }
+            }
+            Notice::Error(message) | Notice::ErrorCoded { message, .. } => Notice::ErrorCoded {
+                code: code.into(),
+                message,
+            },
+            n => n,
+        }
+    }
+
+    pub fn code(&self) -> Option<&str> {
+        match self {
+            Notice::AttentionCoded { code, .. } | Notice::ErrorCoded { code, .. } => Some(code),
+            _ => None,
+        }
+    }
+
+    pub fn message(&self) -> Option<&str> {
+        match self {
+            Notice::Clear => None,
+            Notice::Info(msg) | Notice::Attention(msg) | Notice::Error(msg) => Some(msg),
+            Notice::AttentionCoded { message, .. } | Notice::ErrorCoded { message, .. } => {
+                Some(message)
+            }
+        }
+    }
+
+    fn into_error(self) -> Notice {
+        match self {
+            Notice::Info(msg) | Notice::Attention(msg) => Notice::Error(msg),
+            Notice::AttentionCoded { code, message } => Notice::ErrorCoded { code, message },
+            n => n,
+        }
+    }
+
+    fn into_attention(self) -> Notice {
+        match self {
+            Notice::Error(msg) => Notice::Attention(msg),
+            Notice::ErrorCoded { code, message } => Notice::AttentionCoded { code, message },
+            n => n,
+        }
+    }
+
+    /// Numeric weight consistent with the ordering: `Clear` is 0, `Info` 1,
+    /// `Attention` 2 and `Error` 3.
+    pub fn severity(&self) -> u8 {
+        match self {
+            Notice::Clear => 0,
+            Notice::Info(_) => 1,
+            Notice::Attention(_) | Notice::AttentionCoded { .. } => 2,
+            Notice::Error(_) | Notice::ErrorCoded { .. } => 3,
+        }
+    }
+
+    /// Process exit code for CLI tools: `0` for clear and info, `1` for attention and
+    /// `2` for error.
+    pub fn exit_code(&self) -> i32 {
+        match self.severity() {
+            0 | 1 => 0,
+            2 => 1,
+            _ => 2,
         }
     }
+
+    pub fn is_clear(&self) -> bool {
+        matches!(self, Notice::Clear)
+    }
+
+    pub fn is_error(&self) -> bool {
+        matches!(self, Notice::Error(_) | Notice::ErrorCoded { .. })
+    }
+}
+
+impl Display for Notice {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.message().unwrap_or("OK"))
+    }
 }
 
 impl PartialOrd for Notice {
     fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
-        Some(match (self, other) {
-            (Notice::Clear, Notice::Clear) => std::cmp::Ordering::Equal,
-            (Notice::Clear, _) => std::cmp::Ordering::Less,
-            (_, Notice::Clear) => std::cmp::Ordering::Greater,
-            (Notice::Error(_), Notice::Attention(_)) => std::cmp::Ordering::Greater,
-            (Notice::Attention(_), Notice::Error(_)) => std::cmp::Ordering::Less,
-            (_, _) => std::cmp::Ordering::Equal,
-        })
+        Some(self.cmp(other))
     }
 }
 
 impl Ord for Notice {
     fn cmp(&self, other: &Self) -> std::cmp::Ordering {
-        self.partial_cmp(other).unwrap()
+        self.severity()
+            .cmp(&other.severity())
+            .then_with(|| self.message().cmp(&other.message()))
+            .then_with(|| self.code().cmp(&other.code()))
     }
 }
 
+#[derive(Clone)]
 pub enum CheckerMode<T> {
     Attention(T),
     Error(T),
+    /// Reports errors from the inner checker as attention.
+    Downgrade(T),
 }
 
 impl<T> Checker for CheckerMode<T>
@@ -55,31 +348,95 @@ where
     T: Checker,
 {
     fn check(&self, value: &Value) -> Result<Notice, CheckError> {
+        self.check_with_context(value, &HashMap::new())
+    }
+
+    fn check_with_context(
+        &self,
+        value: &Value,
+        context: &HashMap<String, Value>,
+    ) -> Result<Notice, CheckError> {
         Ok(match self {
-            CheckerMode::Attention(c) => match c.check(value)? {
-                Notice::Clear => Notice::Clear,
-                Notice::Attention(msg) => Notice::Attention(msg),
-                Notice::Error(msg) => Notice::Error(msg),
-            },
-            CheckerMode::Error(c) => match c.check(value)? {
-                Notice::Clear => Notice::Clear,
-                Notice::Attention(msg) => Notice::Error(msg),
-                Notice::Error(msg) => Notice::Error(msg),
-            },
+            CheckerMode::Attention(c) => c.check_with_context(value, context)?,
+            CheckerMode::Error(c) => c.check_with_context(value, context)?.into_error(),
+            CheckerMode::Downgrade(c) => c.check_with_context(value, context)?.into_attention(),
         })
     }
 
-    fn expecting(&self) -> Vec<ValueKind> {
+    fn expecting(&self) -> KindSet {
         match self {
             CheckerMode::Attention(c) => c.expecting(),
             CheckerMode::Error(c) => c.expecting(),
+            CheckerMode::Downgrade(c) => c.expecting(),
+        }
+    }
+
+    fn describe(&self) -> String {
+        match self {
+            CheckerMode::Attention(c) => c.describe(),
+            CheckerMode::Error(c) => c.describe(),
+            CheckerMode::Downgrade(c) => c.describe(),
+        }
+    }
+
+    fn labels(&self) -> Vec<String> {
+        match self {
+            CheckerMode::Attention(c) => c.labels(),
+            CheckerMode::Error(c) => c.labels(),
+            CheckerMode::Downgrade(c) => c.labels(),
+        }
+    }
+
+    fn weight(&self) -> f64 {
+        match self {
+            CheckerMode::Attention(c) => c.weight(),
+            CheckerMode::Error(c) => c.weight(),
+            CheckerMode::Downgrade(c) => c.weight(),
+        }
+    }
+
+    fn messages(&self) -> Vec<String> {
+        match self {
+            CheckerMode::Attention(c) => c.messages(),
+            CheckerMode::Error(c) => c.messages(),
+            CheckerMode::Downgrade(c) => c.messages(),
+        }
+    }
+
+    fn uses_context(&self) -> bool {
+        match self {
+            CheckerMode::Attention(c) => c.uses_context(),
+            CheckerMode::Error(c) => c.uses_context(),
+            CheckerMode::Downgrade(c) => c.uses_context(),
         }
     }
+
+    /// `Downgrade` has no config counterpart.
+    #[cfg(feature = "config")]
+    fn spec(&self) -> Option<RuleSpec> {
+        use crate::config::Mode;
+
+        let (c, mode) = match self {
+            CheckerMode::Attention(c) => (c, Mode::Attention),
+            CheckerMode::Error(c) => (c, Mode::Error),
+            CheckerMode::Downgrade(_) => return None,
+        };
+        c.spec().map(|spec| RuleSpec {
+            mode: Some(mode),
+            ..spec
+        })
+    }
+}
+
+pub trait ContextChecker {
+    fn check(&self, value: &Value, context: &HashMap<String, Value>) -> Result<Notice, CheckError>;
+    fn expecting(&self) -> KindSet;
 }
 
 pub trait SwitchMode: Sized {
     fn into_attention(self) -> CheckerMode<Self>;
     fn into_error(self) -> CheckerMode<Self>;
+    fn into_soft(self) -> CheckerMode<Self>;
 }
 
 impl<T> SwitchMode for T
@@ -93,143 +450,2927 @@ where
     fn into_error(self) -> CheckerMode<Self> {
         CheckerMode::Error(self)
     }
-}
 
-#[derive(Debug, PartialEq, Eq)]
-pub enum CheckError {
-    InvalidKind,
+    fn into_soft(self) -> CheckerMode<Self> {
+        CheckerMode::Downgrade(self)
+    }
 }
 
-impl Display for CheckError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let msg = match self {
-            CheckError::InvalidKind => "Invalid kind",
-        };
-        f.write_str(msg)
+#[derive(Clone)]
+pub struct Labeled<T>(pub T, pub String);
+
+impl<T> Checker for Labeled<T>
+where
+    T: Checker,
+{
+    fn check(&self, value: &Value) -> Result<Notice, CheckError> {
+        self.0.check(value)
     }
-}
 
-impl Error for CheckError {}
+    fn check_with_context(
+        &self,
+        value: &Value,
+        context: &HashMap<String, Value>,
+    ) -> Result<Notice, CheckError> {
+        self.0.check_with_context(value, context)
+    }
 
-pub struct Flatten<T>(Vec<T>);
+    fn expecting(&self) -> KindSet {
+        self.0.expecting()
+    }
 
-impl<T> Flatten<T>
+    fn describe(&self) -> String {
+        self.0.describe()
+    }
+
+    fn labels(&self) -> Vec<String> {
+        let mut labels = vec![self.1.to_string()];
+        labels.extend(self.0.labels());
+        labels
+    }
+
+    fn weight(&self) -> f64 {
+        self.0.weight()
+    }
+
+    fn messages(&self) -> Vec<String> {
+        self.0.messages()
+    }
+
+    fn uses_context(&self) -> bool {
+        self.0.uses_context()
+    }
+}
+
+#[derive(Clone)]
+pub struct Weighted<T>(pub T, pub f64);
+
+impl<T> Checker for Weighted<T>
 where
     T: Checker,
 {
-    fn new<Iter>(x: Iter) -> Result<Self, FlattenError>
-    where
-        Iter: Iterator<Item = T>,
-    {
-        let v = x.collect_vec();
-        if !v.iter().map(|x| x.expecting()).all_equal() {
-            return Err(FlattenError::InvalidKind);
-        }
-        Ok(Flatten(v))
+    fn check(&self, value: &Value) -> Result<Notice, CheckError> {
+        self.0.check(value)
+    }
+
+    fn check_with_context(
+        &self,
+        value: &Value,
+        context: &HashMap<String, Value>,
+    ) -> Result<Notice, CheckError> {
+        self.0.check_with_context(value, context)
+    }
+
+    fn expecting(&self) -> KindSet {
+        self.0.expecting()
+    }
+
+    fn describe(&self) -> String {
+        self.0.describe()
+    }
+
+    fn labels(&self) -> Vec<String> {
+        self.0.labels()
+    }
+
+    fn weight(&self) -> f64 {
+        self.1
+    }
+
+    fn messages(&self) -> Vec<String> {
+        self.0.messages()
+    }
+
+    fn uses_context(&self) -> bool {
+        self.0.uses_context()
     }
 }
 
-impl<T> Checker for Flatten<T>
+/// Replaces the message of any non-clear notice from the inner checker.
+#[derive(Clone)]
+pub struct WithMessage<T>(pub T, pub String);
+
+impl<T> Checker for WithMessage<T>
 where
     T: Checker,
 {
     fn check(&self, value: &Value) -> Result<Notice, CheckError> {
-        let mut res = self
+        self.check_with_context(value, &HashMap::new())
+    }
+
+    fn check_with_context(
+        &self,
+        value: &Value,
+        context: &HashMap<String, Value>,
+    ) -> Result<Notice, CheckError> {
+        Ok(self
             .0
-            .iter()
-            .map(|x| x.check(value))
-            .collect::<Result<Vec<Notice>, CheckError>>()?;
-        res.sort();
-        res.reverse();
-        for n in res {
-            match n {
-                Notice::Clear => {}
-                Notice::Attention(msg) => return Ok(Notice::Attention(msg)),
-                Notice::Error(msg) => return Ok(Notice::Error(msg)),
-            }
-        }
-        Ok(Notice::Clear)
+            .check_with_context(value, context)?
+            .with_message(&self.1))
     }
 
-    fn expecting(&self) -> Vec<ValueKind> {
-        self.0.first().unwrap().expecting()
+    fn expecting(&self) -> KindSet {
+        self.0.expecting()
     }
-}
 
-pub trait IntoFlat<T>: Sized {
-    fn into_flat(self) -> Result<Flatten<T>, FlattenError>;
+    fn describe(&self) -> String {
+        self.0.describe()
+    }
+
+    fn labels(&self) -> Vec<String> {
+        self.0.labels()
+    }
+
+    fn weight(&self) -> f64 {
+        self.0.weight()
+    }
+
+    fn messages(&self) -> Vec<String> {
+        vec![self.1.to_string()]
+    }
+
+    fn uses_context(&self) -> bool {
+        self.0.uses_context()
+    }
 }
 
-impl<T, Q> IntoFlat<T> for Q
+/// Attaches a code to any attention or error notice from the inner checker.
+#[derive(Clone)]
+pub struct Coded<T>(pub T, pub String);
+
+impl<T> Checker for Coded<T>
 where
     T: Checker,
-    Q: Iterator<Item = T>,
 {
-    fn into_flat(self) -> Result<Flatten<T>, FlattenError> {
-        Flatten::new(self)
+    fn check(&self, value: &Value) -> Result<Notice, CheckError> {
+        self.check_with_context(value, &HashMap::new())
+    }
+
+    fn check_with_context(
+        &self,
+        value: &Value,
+        context: &HashMap<String, Value>,
+    ) -> Result<Notice, CheckError> {
+        Ok(self
+            .0
+            .check_with_context(value, context)?
+            .with_code(&self.1))
+    }
+
+    fn expecting(&self) -> KindSet {
+        self.0.expecting()
+    }
+
+    fn describe(&self) -> String {
+        self.0.describe()
+    }
+
+    fn labels(&self) -> Vec<String> {
+        self.0.labels()
+    }
+
+    fn weight(&self) -> f64 {
+        self.0.weight()
+    }
+
+    fn messages(&self) -> Vec<String> {
+        self.0.messages()
+    }
+
+    fn uses_context(&self) -> bool {
+        self.0.uses_context()
     }
 }
 
-#[derive(Debug)]
-pub enum FlattenError {
+#[derive(Debug, PartialEq, Eq)]
+pub enum CheckError {
     InvalidKind,
+    InvalidKindFor {
+        key: String,
+        expected: Vec<ValueKind>,
+        got: ValueKind,
+    },
+    Parse(String),
+    InvalidRule(String),
+    MissingField(String),
 }
 
-impl Display for FlattenError {
+impl Display for CheckError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let msg = match self {
-            FlattenError::InvalidKind => "Invalid kind",
-        };
-        f.write_str(msg)
+        match self {
+            CheckError::InvalidKind => f.write_str("Invalid kind"),
+            CheckError::InvalidKindFor { key, expected, got } => write!(
+                f,
+                "Invalid kind for `{}`: expected {:?}, got {:?}",
+                key, expected, got
+            ),
+            CheckError::Parse(msg) => write!(f, "Parse error: {}", msg),
+            CheckError::InvalidRule(msg) => write!(f, "Invalid rule: {}", msg),
+            CheckError::MissingField(key) => write!(f, "Missing field `{}`", key),
+        }
     }
 }
 
-impl Error for FlattenError {}
+impl Error for CheckError {}
 
-pub enum Checkers {
-    Any,
-    Exact(String, String),
-    Regex(regex::Regex, String),
+/// Reports the worst notice among its children, ties broken by message. Evaluation
+/// stops at the first `Error`, so when several children fail with an error the one
+/// listed first wins.
+#[derive(Clone)]
+pub struct Flatten<T>(Vec<T>);
+
+impl<T> Flatten<T>
+where
+    T: Checker,
+{
+    fn new<Iter>(x: Iter) -> Result<Self, FlattenError>
+    where
+        Iter: Iterator<Item = T>,
+    {
+        let v = x.collect_vec();
+        if v.is_empty() {
+            return Err(FlattenError::Empty);
+        }
+        if Self::kinds(&v).is_none() {
+            return Err(FlattenError::InvalidKind);
+        }
+        Ok(Flatten(v))
+    }
+
+    /// Children that don't accept null must agree on their kinds. Null-accepting ones
+    /// (`Required`, `Optional`, ...) may join them as long as they accept those kinds
+    /// too, and only they see a null value.
+    fn kinds(children: &[T]) -> Option<KindSet> {
+        let (lenient, strict): (Vec<_>, Vec<_>) = children
+            .iter()
+            .map(|x| x.expecting())
+            .partition(|k| k.contains(ValueKind::Null));
+        if !strict.iter().all_equal() {
+            return None;
+        }
+        match strict.first() {
+            Some(&kinds) if lenient.is_empty() => Some(kinds),
+            Some(&kinds) => lenient
+                .iter()
+                .all(|k| k.intersection(kinds) == kinds)
+                .then_some(kinds | KindSet::NULL),
+            None => lenient.into_iter().reduce(KindSet::intersection),
+        }
+    }
+
+    fn applicable<'a>(&'a self, value: &'a Value) -> impl Iterator<Item = &'a T> {
+        self.0
+            .iter()
+            .filter(move |x| x.expecting().contains(value.kind()))
+    }
+
+    pub fn merge(self, other: Flatten<T>) -> Result<Self, FlattenError> {
+        Flatten::new(self.0.into_iter().chain(other.0))
+    }
+
+    pub fn rules(&self) -> Vec<String> {
+        self.0.iter().map(|x| x.describe()).collect()
+    }
+
+    /// `None` unless every child can be written as a config rule.
+    #[cfg(feature = "config")]
+    pub fn specs(&self) -> Option<Vec<RuleSpec>> {
+        self.0.iter().map(|x| x.spec()).collect()
+    }
+
+    /// Runs every child without short-circuiting and returns the non-clear notices in
+    /// child order.
+    pub fn check_all(&self, value: &Value) -> Result<Vec<Notice>, CheckError> {
+        self.check_all_with_context(value, &HashMap::new())
+    }
+
+    pub fn check_all_with_context(
+        &self,
+        value: &Value,
+        context: &HashMap<String, Value>,
+    ) -> Result<Vec<Notice>, CheckError> {
+        if !self.expecting().contains(value.kind()) {
+            return Err(CheckError::InvalidKind);
+        }
+        let mut res = Vec::new();
+        for x in self.applicable(value) {
+            match x.check_with_context(value, context)? {
+                Notice::Clear => {}
+                n => res.push(n),
+            }
+        }
+        Ok(res)
+    }
+
+    fn reduce<I>(notices: I) -> Result<Notice, CheckError>
+    where
+        I: Iterator<Item = Result<Notice, CheckError>>,
+    {
+        let mut res = Vec::new();
+        for n in notices {
+            match n? {
+                n if n.is_error() => return Ok(n),
+                n => res.push(n),
+            }
+        }
+        Ok(Self::worst(res))
+    }
+
+    fn worst(res: Vec<Notice>) -> Notice {
+        res.into_iter().max().unwrap_or(Notice::Clear)
+    }
+}
+
+#[cfg(feature = "parallel")]
+impl<T> Flatten<T>
+where
+    T: Checker + Sync,
+{
+    pub fn check_par(&self, value: &Value) -> Result<Notice, CheckError> {
+        self.check_par_with_context(value, &HashMap::new())
+    }
+
+    pub fn check_par_with_context(
+        &self,
+        value: &Value,
+        context: &HashMap<String, Value>,
+    ) -> Result<Notice, CheckError> {
+        use rayon::prelude::*;
+
+        if !self.expecting().contains(value.kind()) {
+            return Err(CheckError::InvalidKind);
+        }
+        let res = self
+            .0
+            .par_iter()
+            .filter(|x| x.expecting().contains(value.kind()))
+            .map(|x| x.check_with_context(value, context))
+            .collect::<Vec<Result<Notice, CheckError>>>();
+        Self::reduce(res.into_iter())
+    }
+}
+
+impl<T> Checker for Flatten<T>
+where
+    T: Checker,
+{
+    fn check(&self, value: &Value) -> Result<Notice, CheckError> {
+        self.check_with_context(value, &HashMap::new())
+    }
+
+    fn check_with_context(
+        &self,
+        value: &Value,
+        context: &HashMap<String, Value>,
+    ) -> Result<Notice, CheckError> {
+        if !self.expecting().contains(value.kind()) {
+            return Err(CheckError::InvalidKind);
+        }
+        Self::reduce(
+            self.applicable(value)
+                .map(|x| x.check_with_context(value, context)),
+        )
+    }
+
+    fn expecting(&self) -> KindSet {
+        Self::kinds(&self.0).unwrap()
+    }
+
+    fn describe(&self) -> String {
+        self.0.iter().map(|x| x.describe()).join(", ")
+    }
+
+    fn labels(&self) -> Vec<String> {
+        self.0.iter().flat_map(|x| x.labels()).collect()
+    }
+
+    /// The largest weight among the children.
+    fn weight(&self) -> f64 {
+        self.0.iter().map(|x| x.weight()).fold(f64::MIN, f64::max)
+    }
+
+    fn messages(&self) -> Vec<String> {
+        self.0.iter().flat_map(|x| x.messages()).collect()
+    }
+
+    fn uses_context(&self) -> bool {
+        self.0.iter().any(|x| x.uses_context())
+    }
+}
+
+pub trait IntoFlat<T>: Sized {
+    fn into_flat(self) -> Result<Flatten<T>, FlattenError>;
+}
+
+impl<T, Q> IntoFlat<T> for Q
+where
+    T: Checker,
+    Q: Iterator<Item = T>,
+{
+    fn into_flat(self) -> Result<Flatten<T>, FlattenError> {
+        Flatten::new(self)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum FlattenError {
+    InvalidKind,
+    Empty,
+}
+
+impl Display for FlattenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            FlattenError::InvalidKind => "Invalid kind",
+            FlattenError::Empty => "Empty",
+        };
+        f.write_str(msg)
+    }
+}
+
+impl Error for FlattenError {}
+
+type CheckFn = Box<dyn Fn(&Value) -> Result<Notice, CheckError>>;
+
+pub struct FnChecker {
+    f: CheckFn,
+    expecting: KindSet,
+}
+
+impl FnChecker {
+    pub fn new<F>(expecting: impl Into<KindSet>, f: F) -> Self
+    where
+        F: Fn(&Value) -> Result<Notice, CheckError> + 'static,
+    {
+        FnChecker {
+            f: Box::new(f),
+            expecting: expecting.into(),
+        }
+    }
+}
+
+impl Checker for FnChecker {
+    fn check(&self, value: &Value) -> Result<Notice, CheckError> {
+        (self.f)(value)
+    }
+
+    fn expecting(&self) -> KindSet {
+        self.expecting
+    }
+}
+
+/// Stateful: remembers every value it has checked and flags repeats. Uses a `RefCell`,
+/// so it isn't `Sync`; wrap it in a lock to share it across threads.
+pub struct UniqueChecker {
+    seen: RefCell<HashSet<String>>,
+    msg: String,
+}
+
+impl UniqueChecker {
+    pub fn new(msg: impl Into<String>) -> Self {
+        UniqueChecker {
+            seen: RefCell::new(HashSet::new()),
+            msg: msg.into(),
+        }
+    }
+}
+
+impl Checker for UniqueChecker {
+    fn check(&self, value: &Value) -> Result<Notice, CheckError> {
+        Ok(clear_or_attention(
+            self.seen.borrow_mut().insert(value.to_string()),
+            &self.msg,
+        ))
+    }
+
+    fn expecting(&self) -> KindSet {
+        KindSet::NUMBER | KindSet::LITERAL
+    }
+
+    fn describe(&self) -> String {
+        "unique".to_string()
+    }
+}
+
+/// Caches successful results by value. Results that depend on a non-empty context are
+/// not cached. Uses a `RefCell`, so it isn't `Sync`; wrap it in a lock to share it
+/// across threads.
+pub struct Memoized<T> {
+    inner: T,
+    cache: RefCell<HashMap<Value, Notice>>,
+}
+
+impl<T> Memoized<T> {
+    pub fn new(inner: T) -> Self {
+        Memoized {
+            inner,
+            cache: RefCell::new(HashMap::new()),
+        }
+    }
+}
+
+impl<T> Checker for Memoized<T>
+where
+    T: Checker,
+{
+    fn check(&self, value: &Value) -> Result<Notice, CheckError> {
+        self.check_with_context(value, &HashMap::new())
+    }
+
+    fn check_with_context(
+        &self,
+        value: &Value,
+        context: &HashMap<String, Value>,
+    ) -> Result<Notice, CheckError> {
+        if self.inner.uses_context() {
+            return self.inner.check_with_context(value, context);
+        }
+        if let Some(notice) = self.cache.borrow().get(value) {
+            return Ok(notice.clone());
+        }
+        let notice = self.inner.check(value)?;
+        self.cache
+            .borrow_mut()
+            .insert(value.clone(), notice.clone());
+        Ok(notice)
+    }
+
+    fn expecting(&self) -> KindSet {
+        self.inner.expecting()
+    }
+
+    fn describe(&self) -> String {
+        self.inner.describe()
+    }
+
+    fn labels(&self) -> Vec<String> {
+        self.inner.labels()
+    }
+
+    fn weight(&self) -> f64 {
+        self.inner.weight()
+    }
+
+    fn messages(&self) -> Vec<String> {
+        self.inner.messages()
+    }
+
+    fn uses_context(&self) -> bool {
+        self.inner.uses_context()
+    }
+
+    #[cfg(feature = "config")]
+    fn spec(&self) -> Option<RuleSpec> {
+        self.inner.spec()
+    }
+}
+
+/// Non-exhaustive because `GraphemeClusters` only exists with `unicode-segmentation`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum LengthUnit {
+    Chars,
+    Bytes,
+    #[cfg(feature = "unicode-segmentation")]
+    GraphemeClusters,
+}
+
+impl LengthUnit {
+    fn count(&self, value: &str) -> usize {
+        match self {
+            LengthUnit::Chars => value.chars().count(),
+            LengthUnit::Bytes => value.len(),
+            #[cfg(feature = "unicode-segmentation")]
+            LengthUnit::GraphemeClusters => {
+                unicode_segmentation::UnicodeSegmentation::graphemes(value, true).count()
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NormalizeKind {
+    Trim,
+    Lowercase,
+    Uppercase,
+}
+
+impl NormalizeKind {
+    fn apply(&self, value: &str) -> String {
+        match self {
+            NormalizeKind::Trim => value.trim().to_string(),
+            NormalizeKind::Lowercase => value.to_lowercase(),
+            NormalizeKind::Uppercase => value.to_uppercase(),
+        }
+    }
+}
+
+/// A regex that has to match the whole value, so `a|ab` accepts "ab". The anchored form
+/// is compiled once; the source is kept as written for `describe`.
+#[derive(Debug, Clone)]
+pub struct FullRegex {
+    source: String,
+    anchored: regex::Regex,
+}
+
+impl FullRegex {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        regex::Regex::new(pattern)?.try_into()
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+
+    pub fn is_match(&self, value: &str) -> bool {
+        self.anchored.is_match(value)
+    }
+}
+
+impl TryFrom<regex::Regex> for FullRegex {
+    type Error = regex::Error;
+
+    fn try_from(pattern: regex::Regex) -> Result<Self, Self::Error> {
+        let anchored = regex::Regex::new(&format!("^(?:{})$", pattern.as_str()))?;
+        Ok(FullRegex {
+            source: pattern.as_str().to_string(),
+            anchored,
+        })
+    }
+}
+
+impl Display for FullRegex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.source)
+    }
+}
+
+/// Non-exhaustive because several variants only exist behind their cargo feature.
+#[non_exhaustive]
+pub enum Checkers {
+    Any,
+    Exact(String, String),
+    ExactIgnoreCase(String, String),
+    /// `{pattern}` and `{value}` in the message are replaced with the regex source and
+    /// the checked value.
+    Regex(regex::Regex, String),
+    AnyRegex(Vec<regex::Regex>, String),
+    /// Like `Regex`, but the whole value must match.
+    RegexFull(FullRegex, String),
     Between(f64, f64, String),
+    /// Inclusive integer bounds compared without going through `f64`, so large IDs
+    /// keep their precision. Non-integer values fail with `CheckError::Parse`.
+    BetweenInt(i64, i64, String),
+    /// Inclusive bounds; a `None` bound is unbounded on that side.
+    BetweenOpt {
+        min: Option<f64>,
+        max: Option<f64>,
+        msg: String,
+    },
+    #[cfg(feature = "chrono")]
+    DateBetween(chrono::NaiveDate, chrono::NaiveDate, String),
+    /// Clears when the number falls within any of the inclusive ranges.
+    InRanges(Vec<(f64, f64)>, String),
+    GreaterThan(f64, String),
+    GreaterOrEqual(f64, String),
+    LessThan(f64, String),
+    LessOrEqual(f64, String),
+    /// Strictly greater than zero; zero is neither positive nor negative.
+    Positive(String),
+    /// Strictly less than zero.
+    Negative(String),
+    NonZero(String),
+    /// Floats are compared with a small tolerance. A zero divisor fails with
+    /// `CheckError::InvalidRule`.
+    MultipleOf(f64, String),
+    IsInteger(String),
+    IsNumeric(String),
+    MaxDecimals(u32, String),
+    /// A number from 0 to 100 inclusive, optionally followed by `%`.
+    Percentage(String),
+    OneOf(Vec<String>, String),
+    OneOfIgnoreCase(Vec<String>, String),
+    InSet(HashSet<String>, String),
+    /// Bounds are inclusive and counted in characters. A `None` bound is unchecked,
+    /// so `Length { min: None, max: None, .. }` always clears.
+    Length {
+        min: Option<usize>,
+        max: Option<usize>,
+        msg: String,
+    },
+    /// Like `Length`, but counted in the given unit.
+    LengthIn {
+        unit: LengthUnit,
+        min: Option<usize>,
+        max: Option<usize>,
+        msg: String,
+    },
+    IsTrue(String),
+    IsFalse(String),
+    /// Reports the worst notice among the children, with this message.
+    And(Vec<Box<dyn Checker>>, String),
+    /// Clears when any child clears, otherwise reports the mildest notice among the
+    /// children, with this message.
+    Or(Vec<Box<dyn Checker>>, String),
+    Not(Box<dyn Checker>, String),
+    /// Skips the inner checker when the value is empty.
+    Optional(Box<dyn Checker>),
+    Each(Box<dyn Checker>, String),
+    Normalized {
+        transform: NormalizeKind,
+        inner: Box<dyn Checker>,
+    },
+    NotEmpty(String),
+    /// Rejects leading or trailing whitespace.
+    Trimmed(String),
+    Required(String),
+    Contains(String, String),
+    ContainsIgnoreCase(String, String),
+    StartsWith(String, String),
+    EndsWith(String, String),
+    Email(String),
+    /// Canonical 8-4-4-4-12 hex form, case-insensitive.
+    Uuid(String),
+    /// ISO 3166-1 alpha-2, case-insensitive.
+    CountryCode(String),
+    /// ISO 4217 circulating currencies, case-insensitive.
+    CurrencyCode(String),
+    /// Accepts absolute `http`/`https` URLs only.
+    #[cfg(feature = "url")]
+    Url(String),
+    #[cfg(feature = "serde")]
+    ValidJson(String),
+    /// Accepts standard five-field expressions (weekdays `0`-`7` from Sunday) as well as
+    /// the `cron` crate's forms with seconds and an optional year.
+    #[cfg(feature = "cron")]
+    Cron(String),
+    #[cfg(feature = "semver")]
+    SemVer(String),
+    /// Clears when the value is a version satisfying the requirement.
+    #[cfg(feature = "semver")]
+    SemVerReq(semver::VersionReq, String),
+    /// Compares against another submitted field, so it needs the context passed to
+    /// `check_with_context`.
+    EqualsField(String, String),
     Custom(Box<dyn Checker>),
+    CustomContext(Box<dyn ContextChecker>),
+}
+
+impl Checkers {
+    pub fn from_fn<F>(expecting: impl Into<KindSet>, f: F) -> Self
+    where
+        F: Fn(&Value) -> Result<Notice, CheckError> + 'static,
+    {
+        Checkers::Custom(Box::new(FnChecker::new(expecting, f)))
+    }
+
+    pub fn regex_str(pattern: &str, msg: &str) -> Result<Checkers, regex::Error> {
+        Ok(Checkers::Regex(
+            regex::Regex::new(pattern)?,
+            msg.to_string(),
+        ))
+    }
+
+    pub fn regex_full(pattern: &str, msg: &str) -> Result<Checkers, regex::Error> {
+        Ok(Checkers::RegexFull(
+            FullRegex::new(pattern)?,
+            msg.to_string(),
+        ))
+    }
+
+    /// `Checkers` isn't `Clone` because the combinators and `Custom` own boxed trait
+    /// objects. Returns `None` for those; every other variant is cloned.
+    pub fn try_clone(&self) -> Option<Checkers> {
+        Some(match self {
+            Checkers::Any => Checkers::Any,
+            Checkers::Exact(v, msg) => Checkers::Exact(v.clone(), msg.clone()),
+            Checkers::ExactIgnoreCase(v, msg) => Checkers::ExactIgnoreCase(v.clone(), msg.clone()),
+            Checkers::Regex(pattern, msg) => Checkers::Regex(pattern.clone(), msg.clone()),
+            Checkers::AnyRegex(patterns, msg) => Checkers::AnyRegex(patterns.clone(), msg.clone()),
+            Checkers::RegexFull(pattern, msg) => Checkers::RegexFull(pattern.clone(), msg.clone()),
+            Checkers::Between(from, to, msg) => Checkers::Between(*from, *to, msg.clone()),
+            Checkers::BetweenInt(from, to, msg) => Checkers::BetweenInt(*from, *to, msg.clone()),
+            Checkers::BetweenOpt { min, max, msg } => Checkers::BetweenOpt {
+                min: *min,
+                max: *max,
+                msg: msg.clone(),
+            },
+            #[cfg(feature = "chrono")]
+            Checkers::DateBetween(from, to, msg) => Checkers::DateBetween(*from, *to, msg.clone()),
+            Checkers::InRanges(ranges, msg) => Checkers::InRanges(ranges.clone(), msg.clone()),
+            Checkers::GreaterThan(v, msg) => Checkers::GreaterThan(*v, msg.clone()),
+            Checkers::GreaterOrEqual(v, msg) => Checkers::GreaterOrEqual(*v, msg.clone()),
+            Checkers::LessThan(v, msg) => Checkers::LessThan(*v, msg.clone()),
+            Checkers::LessOrEqual(v, msg) => Checkers::LessOrEqual(*v, msg.clone()),
+            Checkers::Positive(msg) => Checkers::Positive(msg.clone()),
+            Checkers::Negative(msg) => Checkers::Negative(msg.clone()),
+            Checkers::NonZero(msg) => Checkers::NonZero(msg.clone()),
+            Checkers::MultipleOf(v, msg) => Checkers::MultipleOf(*v, msg.clone()),
+            Checkers::IsInteger(msg) => Checkers::IsInteger(msg.clone()),
+            Checkers::IsNumeric(msg) => Checkers::IsNumeric(msg.clone()),
+            Checkers::MaxDecimals(v, msg) => Checkers::MaxDecimals(*v, msg.clone()),
+            Checkers::Percentage(msg) => Checkers::Percentage(msg.clone()),
+            Checkers::OneOf(candidates, msg) => Checkers::OneOf(candidates.clone(), msg.clone()),
+            Checkers::OneOfIgnoreCase(candidates, msg) => {
+                Checkers::OneOfIgnoreCase(candidates.clone(), msg.clone())
+            }
+            Checkers::InSet(candidates, msg) => Checkers::InSet(candidates.clone(), msg.clone()),
+            Checkers::Length { min, max, msg } => Checkers::Length {
+                min: *min,
+                max: *max,
+                msg: msg.clone(),
+            },
+            Checkers::LengthIn {
+                unit,
+                min,
+                max,
+                msg,
+            } => Checkers::LengthIn {
+                unit: *unit,
+                min: *min,
+                max: *max,
+                msg: msg.clone(),
+            },
+            Checkers::IsTrue(msg) => Checkers::IsTrue(msg.clone()),
+            Checkers::IsFalse(msg) => Checkers::IsFalse(msg.clone()),
+            Checkers::NotEmpty(msg) => Checkers::NotEmpty(msg.clone()),
+            Checkers::Trimmed(msg) => Checkers::Trimmed(msg.clone()),
+            Checkers::Required(msg) => Checkers::Required(msg.clone()),
+            Checkers::Contains(v, msg) => Checkers::Contains(v.clone(), msg.clone()),
+            Checkers::ContainsIgnoreCase(v, msg) => {
+                Checkers::ContainsIgnoreCase(v.clone(), msg.clone())
+            }
+            Checkers::StartsWith(v, msg) => Checkers::StartsWith(v.clone(), msg.clone()),
+            Checkers::EndsWith(v, msg) => Checkers::EndsWith(v.clone(), msg.clone()),
+            Checkers::Email(msg) => Checkers::Email(msg.clone()),
+            Checkers::Uuid(msg) => Checkers::Uuid(msg.clone()),
+            Checkers::CountryCode(msg) => Checkers::CountryCode(msg.clone()),
+            Checkers::CurrencyCode(msg) => Checkers::CurrencyCode(msg.clone()),
+            #[cfg(feature = "url")]
+            Checkers::Url(msg) => Checkers::Url(msg.clone()),
+            #[cfg(feature = "serde")]
+            Checkers::ValidJson(msg) => Checkers::ValidJson(msg.clone()),
+            #[cfg(feature = "cron")]
+            Checkers::Cron(msg) => Checkers::Cron(msg.clone()),
+            #[cfg(feature = "semver")]
+            Checkers::SemVer(msg) => Checkers::SemVer(msg.clone()),
+            #[cfg(feature = "semver")]
+            Checkers::SemVerReq(req, msg) => Checkers::SemVerReq(req.clone(), msg.clone()),
+            Checkers::EqualsField(key, msg) => Checkers::EqualsField(key.clone(), msg.clone()),
+            Checkers::And(_, _)
+            | Checkers::Or(_, _)
+            | Checkers::Not(_, _)
+            | Checkers::Optional(_)
+            | Checkers::Each(_, _)
+            | Checkers::Normalized { .. }
+            | Checkers::Custom(_)
+            | Checkers::CustomContext(_) => return None,
+        })
+    }
 }
 
 impl Checker for Checkers {
     fn check(&self, value: &Value) -> Result<Notice, CheckError> {
+        self.check_with_context(value, &HashMap::new())
+    }
+
+    fn check_with_context(
+        &self,
+        value: &Value,
+        context: &HashMap<String, Value>,
+    ) -> Result<Notice, CheckError> {
         match self {
             Checkers::Any => Ok(Notice::Clear),
             Checkers::Exact(v, msg) => Ok(match v == &value.to_string() {
                 true => Notice::Clear,
                 false => Notice::Attention(msg.to_string()),
             }),
+            Checkers::ExactIgnoreCase(v, msg) => Ok(clear_or_attention(
+                v.to_lowercase() == value.to_string().to_lowercase(),
+                msg,
+            )),
             Checkers::Regex(pattern, msg) => Ok(match pattern.is_match(&value.to_string()) {
                 true => Notice::Clear,
-                false => Notice::Attention(msg.to_string()),
+                false => Notice::Attention(
+                    msg.replace("{pattern}", pattern.as_str())
+                        .replace("{value}", &value.to_string()),
+                ),
+            }),
+            Checkers::RegexFull(pattern, msg) => Ok(clear_or_attention(
+                pattern.is_match(&value.to_string()),
+                msg,
+            )),
+            Checkers::AnyRegex(patterns, msg) => Ok(clear_or_attention(
+                patterns.iter().any(|p| p.is_match(&value.to_string())),
+                msg,
+            )),
+            Checkers::Between(from, to, msg) => {
+                check_number(value, msg, |v| *from <= v && v <= *to)
+            }
+            Checkers::BetweenInt(from, to, msg) => {
+                check_integer(value, msg, |v| *from <= v && v <= *to)
+            }
+            Checkers::BetweenOpt { min, max, msg } => check_number(value, msg, |v| {
+                min.is_none_or(|min| min <= v) && max.is_none_or(|max| v <= max)
             }),
-            Checkers::Between(from, to, msg) => match value.is_kind_of(ValueKind::Number) {
+            #[cfg(feature = "chrono")]
+            Checkers::DateBetween(from, to, msg) => match value.is_kind_of(ValueKind::DateTime) {
                 true => {
-                    let v: f64 = value.try_into().unwrap();
-                    Ok({
-                        if *from <= v && v <= *to {
-                            Notice::Clear
-                        } else {
-                            Notice::Attention(msg.to_string())
-                        }
-                    })
+                    let v: chrono::NaiveDate = value.try_into().map_err(CheckError::Parse)?;
+                    Ok(clear_or_attention(*from <= v && v <= *to, msg))
                 }
                 false => Err(CheckError::InvalidKind),
             },
-            Checkers::Custom(n) => n.check(value),
+            Checkers::InRanges(ranges, msg) => check_number(value, msg, |v| {
+                ranges.iter().any(|(from, to)| *from <= v && v <= *to)
+            }),
+            Checkers::GreaterThan(min, msg) => check_number(value, msg, |v| v > *min),
+            Checkers::GreaterOrEqual(min, msg) => check_number(value, msg, |v| v >= *min),
+            Checkers::LessThan(max, msg) => check_number(value, msg, |v| v < *max),
+            Checkers::LessOrEqual(max, msg) => check_number(value, msg, |v| v <= *max),
+            Checkers::Positive(msg) => check_number(value, msg, |v| v > 0.0),
+            Checkers::Negative(msg) => check_number(value, msg, |v| v < 0.0),
+            Checkers::NonZero(msg) => check_number(value, msg, |v| v != 0.0),
+            Checkers::MultipleOf(divisor, _) if *divisor == 0.0 => Err(CheckError::InvalidRule(
+                "divisor of MultipleOf must not be zero".to_string(),
+            )),
+            Checkers::MultipleOf(divisor, msg) => {
+                check_number(value, msg, |v| is_multiple(v, *divisor))
+            }
+            Checkers::IsInteger(msg) => check_number(value, msg, |v| v.fract() == 0.0),
+            Checkers::IsNumeric(msg) => Ok(clear_or_attention(
+                value.to_string().parse::<f64>().is_ok(),
+                msg,
+            )),
+            Checkers::Percentage(msg) => {
+                let value = value.to_string();
+                Ok(clear_or_attention(
+                    value
+                        .strip_suffix('%')
+                        .unwrap_or(&value)
+                        .parse::<f64>()
+                        .is_ok_and(|v| (0.0..=100.0).contains(&v)),
+                    msg,
+                ))
+            }
+            Checkers::MaxDecimals(limit, msg) => match value.is_kind_of(ValueKind::Number) {
+                true => {
+                    let decimals = value
+                        .to_string()
+                        .split_once('.')
+                        .map_or(0, |(_, fraction)| fraction.chars().count());
+                    Ok(clear_or_attention(decimals <= *limit as usize, msg))
+                }
+                false => Err(CheckError::InvalidKind),
+            },
+            Checkers::OneOf(candidates, msg) => Ok(clear_or_attention(
+                candidates.contains(&value.to_string()),
+                msg,
+            )),
+            Checkers::OneOfIgnoreCase(candidates, msg) => {
+                let value = value.to_string().to_lowercase();
+                Ok(clear_or_attention(
+                    candidates.iter().any(|c| c.to_lowercase() == value),
+                    msg,
+                ))
+            }
+            Checkers::InSet(candidates, msg) => Ok(clear_or_attention(
+                candidates.contains(&value.to_string()),
+                msg,
+            )),
+            Checkers::Length { min, max, msg } => {
+                let len = value.to_string().chars().count();
+                Ok(clear_or_attention(
+                    min.is_none_or(|min| min <= len) && max.is_none_or(|max| len <= max),
+                    msg,
+                ))
+            }
+            Checkers::LengthIn {
+                unit,
+                min,
+                max,
+                msg,
+            } => {
+                let len = unit.count(&value.to_string());
+                Ok(clear_or_attention(
+                    min.is_none_or(|min| min <= len) && max.is_none_or(|max| len <= max),
+                    msg,
+                ))
+            }
+            Checkers::IsTrue(msg) => check_boolean(value, msg, |v| v),
+            Checkers::IsFalse(msg) => check_boolean(value, msg, |v| !v),
+            Checkers::And(children, msg) => Ok(check_children(children, value, context)?
+                .into_iter()
+                .max()
+                .map_or(Notice::Clear, |n| n.with_message(msg))),
+            Checkers::Or(children, msg) => Ok(check_children(children, value, context)?
+                .into_iter()
+                .min()
+                .map_or(Notice::Attention(msg.to_string()), |n| n.with_message(msg))),
+            Checkers::Not(inner, msg) => Ok(clear_or_attention(
+                inner.check_with_context(value, context)? != Notice::Clear,
+                msg,
+            )),
+            Checkers::Optional(inner) => {
+                match value.is_kind_of(ValueKind::Null) || value.to_string().is_empty() {
+                    true => Ok(Notice::Clear),
+                    false => inner.check_with_context(value, context),
+                }
+            }
+            Checkers::Each(inner, msg) => Ok(value
+                .to_string()
+                .split(',')
+                .map(|x| inner.check_with_context(&Value::new(x.trim(), value.kind()), context))
+                .collect::<Result<Vec<_>, _>>()?
+                .into_iter()
+                .max()
+                .map_or(Notice::Clear, |n| n.with_message(msg))),
+            Checkers::Normalized { transform, inner } => inner.check_with_context(
+                &Value::new(transform.apply(&value.to_string()), value.kind()),
+                context,
+            ),
+            Checkers::Required(msg) => {
+                Ok(clear_or_attention(!value.is_kind_of(ValueKind::Null), msg))
+            }
+            Checkers::NotEmpty(msg) => Ok(clear_or_attention(
+                !value.to_string().trim().is_empty(),
+                msg,
+            )),
+            Checkers::Trimmed(msg) => {
+                let s = value.to_string();
+                Ok(clear_or_attention(s == s.trim(), msg))
+            }
+            Checkers::Contains(needle, msg) => Ok(clear_or_attention(
+                value.to_string().contains(needle.as_str()),
+                msg,
+            )),
+            Checkers::ContainsIgnoreCase(needle, msg) => Ok(clear_or_attention(
+                value
+                    .to_string()
+                    .to_lowercase()
+                    .contains(&needle.to_lowercase()),
+                msg,
+            )),
+            Checkers::StartsWith(prefix, msg) => Ok(clear_or_attention(
+                value.to_string().starts_with(prefix.as_str()),
+                msg,
+            )),
+            Checkers::EndsWith(suffix, msg) => Ok(clear_or_attention(
+                value.to_string().ends_with(suffix.as_str()),
+                msg,
+            )),
+            Checkers::Uuid(msg) => Ok(clear_or_attention(is_uuid(&value.to_string()), msg)),
+            Checkers::CountryCode(msg) => Ok(clear_or_attention(
+                is_code(iso::COUNTRY_CODES, &value.to_string()),
+                msg,
+            )),
+            Checkers::CurrencyCode(msg) => Ok(clear_or_attention(
+                is_code(iso::CURRENCY_CODES, &value.to_string()),
+                msg,
+            )),
+            Checkers::Email(msg) => Ok(clear_or_attention(
+                email_pattern().is_match(&value.to_string()),
+                msg,
+            )),
+            #[cfg(feature = "url")]
+            Checkers::Url(msg) => Ok(clear_or_attention(
+                url::Url::parse(&value.to_string())
+                    .is_ok_and(|url| matches!(url.scheme(), "http" | "https") && url.has_host()),
+                msg,
+            )),
+            #[cfg(feature = "cron")]
+            Checkers::Cron(msg) => {
+                let expr = normalize_cron(&value.to_string());
+                Ok(clear_or_attention(
+                    expr.parse::<cron::Schedule>().is_ok(),
+                    msg,
+                ))
+            }
+            #[cfg(feature = "semver")]
+            Checkers::SemVer(msg) => Ok(clear_or_attention(
+                semver::Version::parse(&value.to_string()).is_ok(),
+                msg,
+            )),
+            #[cfg(feature = "semver")]
+            Checkers::SemVerReq(req, msg) => Ok(clear_or_attention(
+                semver::Version::parse(&value.to_string()).is_ok_and(|v| req.matches(&v)),
+                msg,
+            )),
+            #[cfg(feature = "serde")]
+            Checkers::ValidJson(msg) => Ok(clear_or_attention(
+                serde_json::from_str::<serde_json::Value>(&value.to_string()).is_ok(),
+                msg,
+            )),
+            Checkers::EqualsField(key, msg) => match context.get(key) {
+                Some(other) => Ok(clear_or_attention(
+                    value.to_string() == other.to_string(),
+                    msg,
+                )),
+                None => Err(CheckError::MissingField(key.to_string())),
+            },
+            Checkers::Custom(n) => n.check_with_context(value, context),
+            Checkers::CustomContext(n) => n.check(value, context),
         }
     }
 
-    fn expecting(&self) -> Vec<ValueKind> {
+    fn expecting(&self) -> KindSet {
         match self {
-            Checkers::Any => vec![ValueKind::Number, ValueKind::Literal],
-            Checkers::Exact(_, _) => vec![ValueKind::Number, ValueKind::Literal],
-            Checkers::Regex(_, _) => vec![ValueKind::Number, ValueKind::Literal],
-            Checkers::Between(_, _, _) => vec![ValueKind::Number],
+            Checkers::Any => {
+                KindSet::NUMBER
+                    | KindSet::LITERAL
+                    | KindSet::BOOLEAN
+                    | KindSet::DATE_TIME
+                    | KindSet::NULL
+            }
+            Checkers::Exact(_, _) => KindSet::NUMBER | KindSet::LITERAL,
+            Checkers::ExactIgnoreCase(_, _) => KindSet::NUMBER | KindSet::LITERAL,
+            Checkers::Regex(_, _) => KindSet::NUMBER | KindSet::LITERAL,
+            Checkers::AnyRegex(_, _) => KindSet::NUMBER | KindSet::LITERAL,
+            Checkers::RegexFull(_, _) => KindSet::NUMBER | KindSet::LITERAL,
+            Checkers::Between(_, _, _) => KindSet::NUMBER,
+            Checkers::BetweenInt(_, _, _) => KindSet::NUMBER,
+            Checkers::BetweenOpt { .. } => KindSet::NUMBER,
+            #[cfg(feature = "chrono")]
+            Checkers::DateBetween(_, _, _) => KindSet::DATE_TIME,
+            Checkers::InRanges(_, _) => KindSet::NUMBER,
+            Checkers::GreaterThan(_, _) => KindSet::NUMBER,
+            Checkers::GreaterOrEqual(_, _) => KindSet::NUMBER,
+            Checkers::LessThan(_, _) => KindSet::NUMBER,
+            Checkers::LessOrEqual(_, _) => KindSet::NUMBER,
+            Checkers::Positive(_) => KindSet::NUMBER,
+            Checkers::Negative(_) => KindSet::NUMBER,
+            Checkers::NonZero(_) => KindSet::NUMBER,
+            Checkers::MultipleOf(_, _) => KindSet::NUMBER,
+            Checkers::IsInteger(_) => KindSet::NUMBER,
+            Checkers::IsNumeric(_) => KindSet::NUMBER | KindSet::LITERAL,
+            Checkers::MaxDecimals(_, _) => KindSet::NUMBER,
+            Checkers::Percentage(_) => KindSet::NUMBER | KindSet::LITERAL,
+            Checkers::OneOf(_, _) => KindSet::NUMBER | KindSet::LITERAL,
+            Checkers::OneOfIgnoreCase(_, _) => KindSet::NUMBER | KindSet::LITERAL,
+            Checkers::InSet(_, _) => KindSet::NUMBER | KindSet::LITERAL,
+            Checkers::Length { .. } => KindSet::LITERAL,
+            Checkers::LengthIn { .. } => KindSet::LITERAL,
+            Checkers::IsTrue(_) => KindSet::BOOLEAN,
+            Checkers::IsFalse(_) => KindSet::BOOLEAN,
+            Checkers::And(children, _) => {
+                children.first().map_or(KindSet::EMPTY, |c| c.expecting())
+            }
+            Checkers::Or(children, _) => children.first().map_or(KindSet::EMPTY, |c| c.expecting()),
+            Checkers::Not(inner, _) => inner.expecting(),
+            Checkers::Optional(inner) => inner.expecting() | KindSet::NULL,
+            Checkers::Each(inner, _) => inner.expecting(),
+            Checkers::Normalized { inner, .. } => inner.expecting(),
+            Checkers::NotEmpty(_) => KindSet::LITERAL,
+            Checkers::Trimmed(_) => KindSet::LITERAL,
+            Checkers::Required(_) => {
+                KindSet::NUMBER
+                    | KindSet::LITERAL
+                    | KindSet::BOOLEAN
+                    | KindSet::DATE_TIME
+                    | KindSet::NULL
+            }
+            Checkers::Contains(_, _) => KindSet::LITERAL,
+            Checkers::ContainsIgnoreCase(_, _) => KindSet::LITERAL,
+            Checkers::StartsWith(_, _) => KindSet::LITERAL,
+            Checkers::EndsWith(_, _) => KindSet::LITERAL,
+            Checkers::Email(_) => KindSet::LITERAL,
+            Checkers::Uuid(_) => KindSet::LITERAL,
+            Checkers::CountryCode(_) => KindSet::LITERAL,
+            Checkers::CurrencyCode(_) => KindSet::LITERAL,
+            #[cfg(feature = "url")]
+            Checkers::Url(_) => KindSet::LITERAL,
+            #[cfg(feature = "serde")]
+            Checkers::ValidJson(_) => KindSet::LITERAL,
+            #[cfg(feature = "cron")]
+            Checkers::Cron(_) => KindSet::LITERAL,
+            #[cfg(feature = "semver")]
+            Checkers::SemVer(_) => KindSet::LITERAL,
+            #[cfg(feature = "semver")]
+            Checkers::SemVerReq(_, _) => KindSet::LITERAL,
+            Checkers::EqualsField(_, _) => KindSet::NUMBER | KindSet::LITERAL,
             Checkers::Custom(inner) => inner.expecting(),
+            Checkers::CustomContext(inner) => inner.expecting(),
         }
     }
+
+    fn describe(&self) -> String {
+        match self {
+            Checkers::Any => "any value".to_string(),
+            Checkers::Exact(v, _) => format!("exactly \"{}\"", v),
+            Checkers::ExactIgnoreCase(v, _) => format!("exactly \"{}\" ignoring case", v),
+            Checkers::Regex(pattern, _) => format!("matching /{}/", pattern),
+            Checkers::RegexFull(pattern, _) => format!("fully matching /{}/", pattern),
+            Checkers::AnyRegex(patterns, _) => format!(
+                "matching any of {}",
+                patterns.iter().map(|p| format!("/{}/", p)).join(", ")
+            ),
+            Checkers::Between(from, to, _) => describe_bounds(Some(from), Some(to)),
+            Checkers::BetweenInt(from, to, _) => describe_bounds(Some(from), Some(to)),
+            Checkers::BetweenOpt { min, max, .. } => describe_bounds(min.as_ref(), max.as_ref()),
+            #[cfg(feature = "chrono")]
+            Checkers::DateBetween(from, to, _) => describe_bounds(Some(from), Some(to)),
+            Checkers::InRanges(ranges, _) => ranges
+                .iter()
+                .map(|(from, to)| describe_bounds(Some(from), Some(to)))
+                .join(" or "),
+            Checkers::GreaterThan(min, _) => format!("greater than {}", min),
+            Checkers::GreaterOrEqual(min, _) => format!("at least {}", min),
+            Checkers::LessThan(max, _) => format!("less than {}", max),
+            Checkers::LessOrEqual(max, _) => format!("at most {}", max),
+            Checkers::Positive(_) => "positive".to_string(),
+            Checkers::Negative(_) => "negative".to_string(),
+            Checkers::NonZero(_) => "non-zero".to_string(),
+            Checkers::MultipleOf(divisor, _) => format!("multiple of {}", divisor),
+            Checkers::IsInteger(_) => "an integer".to_string(),
+            Checkers::IsNumeric(_) => "numeric".to_string(),
+            Checkers::MaxDecimals(limit, _) => format!("at most {} decimal places", limit),
+            Checkers::Percentage(_) => "a percentage".to_string(),
+            Checkers::OneOf(candidates, _) => format!(
+                "one of {}",
+                candidates.iter().map(|c| format!("\"{}\"", c)).join(", ")
+            ),
+            Checkers::OneOfIgnoreCase(candidates, _) => format!(
+                "one of {} ignoring case",
+                candidates.iter().map(|c| format!("\"{}\"", c)).join(", ")
+            ),
+            Checkers::InSet(candidates, _) => format!(
+                "one of {}",
+                candidates
+                    .iter()
+                    .sorted()
+                    .map(|c| format!("\"{}\"", c))
+                    .join(", ")
+            ),
+            Checkers::Length { min, max, .. } => {
+                format!("length {}", describe_bounds(min.as_ref(), max.as_ref()))
+            }
+            Checkers::LengthIn { unit, min, max, .. } => format!(
+                "length in {:?} {}",
+                unit,
+                describe_bounds(min.as_ref(), max.as_ref())
+            ),
+            Checkers::IsTrue(_) => "true".to_string(),
+            Checkers::IsFalse(_) => "false".to_string(),
+            Checkers::And(children, _) => {
+                format!("({})", children.iter().map(|c| c.describe()).join(" and "))
+            }
+            Checkers::Or(children, _) => {
+                format!("({})", children.iter().map(|c| c.describe()).join(" or "))
+            }
+            Checkers::Not(inner, _) => format!("not {}", inner.describe()),
+            Checkers::Optional(inner) => format!("empty or {}", inner.describe()),
+            Checkers::Each(inner, _) => format!("each {}", inner.describe()),
+            Checkers::Normalized { transform, inner } => {
+                format!("{} after {:?}", inner.describe(), transform)
+            }
+            Checkers::NotEmpty(_) => "not empty".to_string(),
+            Checkers::Trimmed(_) => "no leading or trailing whitespace".to_string(),
+            Checkers::Required(_) => "required".to_string(),
+            Checkers::Contains(needle, _) => format!("containing \"{}\"", needle),
+            Checkers::ContainsIgnoreCase(needle, _) => {
+                format!("containing \"{}\" ignoring case", needle)
+            }
+            Checkers::StartsWith(prefix, _) => format!("starting with \"{}\"", prefix),
+            Checkers::EndsWith(suffix, _) => format!("ending with \"{}\"", suffix),
+            Checkers::Email(_) => "an email address".to_string(),
+            Checkers::Uuid(_) => "a UUID".to_string(),
+            Checkers::CountryCode(_) => "an ISO 3166 country code".to_string(),
+            Checkers::CurrencyCode(_) => "an ISO 4217 currency code".to_string(),
+            #[cfg(feature = "url")]
+            Checkers::Url(_) => "an http(s) URL".to_string(),
+            #[cfg(feature = "serde")]
+            Checkers::ValidJson(_) => "valid JSON".to_string(),
+            #[cfg(feature = "cron")]
+            Checkers::Cron(_) => "a cron expression".to_string(),
+            #[cfg(feature = "semver")]
+            Checkers::SemVer(_) => "a semantic version".to_string(),
+            #[cfg(feature = "semver")]
+            Checkers::SemVerReq(req, _) => format!("a version matching {}", req),
+            Checkers::EqualsField(key, _) => format!("equal to field `{}`", key),
+            Checkers::Custom(inner) => inner.describe(),
+            Checkers::CustomContext(_) => "custom check".to_string(),
+        }
+    }
+
+    fn labels(&self) -> Vec<String> {
+        match self {
+            Checkers::Custom(inner) => inner.labels(),
+            _ => vec![],
+        }
+    }
+
+    fn weight(&self) -> f64 {
+        match self {
+            Checkers::Custom(inner) => inner.weight(),
+            _ => 1.0,
+        }
+    }
+
+    /// Composite checkers and `Custom` report no messages.
+    fn messages(&self) -> Vec<String> {
+        let msg = match self {
+            Checkers::Any
+            | Checkers::And(_, _)
+            | Checkers::Or(_, _)
+            | Checkers::Not(_, _)
+            | Checkers::Optional(_)
+            | Checkers::Each(_, _)
+            | Checkers::Normalized { .. }
+            | Checkers::Custom(_)
+            | Checkers::CustomContext(_) => return vec![],
+            Checkers::Exact(_, msg)
+            | Checkers::ExactIgnoreCase(_, msg)
+            | Checkers::Regex(_, msg)
+            | Checkers::AnyRegex(_, msg)
+            | Checkers::RegexFull(_, msg)
+            | Checkers::Between(_, _, msg)
+            | Checkers::BetweenInt(_, _, msg)
+            | Checkers::BetweenOpt { msg, .. }
+            | Checkers::InRanges(_, msg)
+            | Checkers::GreaterThan(_, msg)
+            | Checkers::GreaterOrEqual(_, msg)
+            | Checkers::LessThan(_, msg)
+            | Checkers::LessOrEqual(_, msg)
+            | Checkers::Positive(msg)
+            | Checkers::Negative(msg)
+            | Checkers::NonZero(msg)
+            | Checkers::MultipleOf(_, msg)
+            | Checkers::IsInteger(msg)
+            | Checkers::IsNumeric(msg)
+            | Checkers::MaxDecimals(_, msg)
+            | Checkers::Percentage(msg)
+            | Checkers::OneOf(_, msg)
+            | Checkers::OneOfIgnoreCase(_, msg)
+            | Checkers::InSet(_, msg)
+            | Checkers::Length { msg, .. }
+            | Checkers::LengthIn { msg, .. }
+            | Checkers::IsTrue(msg)
+            | Checkers::IsFalse(msg)
+            | Checkers::NotEmpty(msg)
+            | Checkers::Trimmed(msg)
+            | Checkers::Required(msg)
+            | Checkers::Contains(_, msg)
+            | Checkers::ContainsIgnoreCase(_, msg)
+            | Checkers::StartsWith(_, msg)
+            | Checkers::EndsWith(_, msg)
+            | Checkers::Email(msg)
+            | Checkers::Uuid(msg)
+            | Checkers::CountryCode(msg)
+            | Checkers::CurrencyCode(msg)
+            | Checkers::EqualsField(_, msg) => msg,
+            #[cfg(feature = "chrono")]
+            Checkers::DateBetween(_, _, msg) => msg,
+            #[cfg(feature = "url")]
+            Checkers::Url(msg) => msg,
+            #[cfg(feature = "serde")]
+            Checkers::ValidJson(msg) => msg,
+            #[cfg(feature = "cron")]
+            Checkers::Cron(msg) => msg,
+            #[cfg(feature = "semver")]
+            Checkers::SemVer(msg) => msg,
+            #[cfg(feature = "semver")]
+            Checkers::SemVerReq(_, msg) => msg,
+        };
+        vec![msg.to_string()]
+    }
+
+    fn uses_context(&self) -> bool {
+        match self {
+            Checkers::EqualsField(_, _) | Checkers::CustomContext(_) => true,
+            Checkers::And(children, _) | Checkers::Or(children, _) => {
+                children.iter().any(|c| c.uses_context())
+            }
+            Checkers::Not(inner, _)
+            | Checkers::Optional(inner)
+            | Checkers::Each(inner, _)
+            | Checkers::Normalized { inner, .. }
+            | Checkers::Custom(inner) => inner.uses_context(),
+            _ => false,
+        }
+    }
+
+    #[cfg(feature = "config")]
+    fn spec(&self) -> Option<RuleSpec> {
+        RuleSpec::from_checker(self)
+    }
+}
+
+/// The remainder carries the step's representation error once per step, so the
+/// tolerance grows with the larger of the value and the step rather than being fixed.
+fn is_multiple(value: f64, step: f64) -> bool {
+    let step = step.abs();
+    let rem = value.abs() % step;
+    rem.min(step - rem) <= value.abs().max(step) * 4.0 * f64::EPSILON
+}
+
+fn check_number<F>(value: &Value, msg: &str, f: F) -> Result<Notice, CheckError>
+where
+    F: Fn(f64) -> bool,
+{
+    match value.is_kind_of(ValueKind::Number) {
+        true => {
+            let v: f64 = value.try_into().map_err(CheckError::Parse)?;
+            Ok(clear_or_attention(f(v), msg))
+        }
+        false => Err(CheckError::InvalidKind),
+    }
+}
+
+fn check_integer<F>(value: &Value, msg: &str, f: F) -> Result<Notice, CheckError>
+where
+    F: Fn(i64) -> bool,
+{
+    match value.is_kind_of(ValueKind::Number) {
+        true => {
+            let v: i64 = value.try_into().map_err(CheckError::Parse)?;
+            Ok(clear_or_attention(f(v), msg))
+        }
+        false => Err(CheckError::InvalidKind),
+    }
+}
+
+fn check_boolean<F>(value: &Value, msg: &str, f: F) -> Result<Notice, CheckError>
+where
+    F: Fn(bool) -> bool,
+{
+    match value.is_kind_of(ValueKind::Boolean) {
+        true => {
+            let v: bool = value.try_into().map_err(CheckError::Parse)?;
+            Ok(clear_or_attention(f(v), msg))
+        }
+        false => Err(CheckError::InvalidKind),
+    }
+}
+
+fn email_pattern() -> &'static regex::Regex {
+    static PATTERN: OnceLock<regex::Regex> = OnceLock::new();
+    PATTERN.get_or_init(|| regex::Regex::new(r"^[^\s@]+@[^\s@.]+(\.[^\s@.]+)+$").unwrap())
+}
+
+/// Rewrites a five-field expression in the `cron` crate's form: a leading seconds field
+/// and weekdays counted `1`-`7` from Sunday instead of `0`-`7`.
+#[cfg(feature = "cron")]
+fn normalize_cron(expr: &str) -> String {
+    let fields = expr.split_whitespace().collect_vec();
+    if fields.len() != 5 {
+        return expr.to_string();
+    }
+    let weekdays = fields[4].split(',').map(shift_weekday).join(",");
+    format!("0 {} {}", fields[..4].join(" "), weekdays)
+}
+
+#[cfg(feature = "cron")]
+fn shift_weekday(item: &str) -> String {
+    let (range, step) = match item.split_once('/') {
+        Some((range, step)) => (range, Some(step)),
+        None => (item, None),
+    };
+    let suffix = step.map_or(String::new(), |s| format!("/{}", s));
+    let bounds = match range.split_once('-') {
+        Some((a, b)) => a.parse::<u8>().ok().zip(b.parse::<u8>().ok().map(Some)),
+        None => range.parse::<u8>().ok().map(|a| (a, None)),
+    };
+    match bounds {
+        Some((a, None)) if a <= 7 => format!("{}{}", a % 7 + 1, suffix),
+        Some((0, Some(7))) => format!("1-7{}", suffix),
+        // `a-7` ends on Sunday, which the crate numbers first.
+        Some((a, Some(7))) if a < 7 => {
+            let step = step.and_then(|s| s.parse::<u8>().ok()).unwrap_or(1);
+            let sunday = step > 0 && (7 - a) % step == 0;
+            format!("{}-7{}{}", a + 1, suffix, if sunday { ",1" } else { "" })
+        }
+        Some((a, Some(b))) if a <= b && b < 7 => format!("{}-{}{}", a + 1, b + 1, suffix),
+        _ => item.to_string(),
+    }
+}
+
+fn is_code(codes: &[&str], value: &str) -> bool {
+    codes
+        .binary_search(&value.to_ascii_uppercase().as_str())
+        .is_ok()
+}
+
+fn is_uuid(value: &str) -> bool {
+    value.len() == 36
+        && value.char_indices().all(|(i, c)| match i {
+            8 | 13 | 18 | 23 => c == '-',
+            _ => c.is_ascii_hexdigit(),
+        })
+}
+
+fn check_children(
+    children: &[Box<dyn Checker>],
+    value: &Value,
+    context: &HashMap<String, Value>,
+) -> Result<Vec<Notice>, CheckError> {
+    if !children.iter().map(|x| x.expecting()).all_equal() {
+        return Err(CheckError::InvalidKind);
+    }
+    children
+        .iter()
+        .map(|x| x.check_with_context(value, context))
+        .collect()
+}
+
+fn describe_bounds<T>(min: Option<&T>, max: Option<&T>) -> String
+where
+    T: Display,
+{
+    match (min, max) {
+        (Some(min), Some(max)) => format!("between {} and {}", min, max),
+        (Some(min), None) => format!("at least {}", min),
+        (None, Some(max)) => format!("at most {}", max),
+        (None, None) => "unbounded".to_string(),
+    }
+}
+
+fn clear_or_attention(ok: bool, msg: &str) -> Notice {
+    match ok {
+        true => Notice::Clear,
+        false => Notice::Attention(msg.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use itertools::Itertools;
+
+    use super::{
+        CheckError, Checker, Checkers, Coded, FlattenError, IntoFlat, LengthUnit, Memoized,
+        NormalizeKind, Notice, SwitchMode, UniqueChecker, WithMessage,
+    };
+    use crate::value::{KindSet, Value};
+
+    #[test]
+    fn test_greater_than() {
+        let checker = Checkers::GreaterThan(18.0, "too young".to_string());
+        assert_eq!(checker.check(&19.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&18.into()),
+            Ok(Notice::Attention("too young".to_string()))
+        );
+        assert_eq!(checker.check(&"19".into()), Err(CheckError::InvalidKind));
+    }
+
+    #[test]
+    fn test_greater_or_equal() {
+        let checker = Checkers::GreaterOrEqual(18.0, "too young".to_string());
+        assert_eq!(checker.check(&18.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&17.9.into()),
+            Ok(Notice::Attention("too young".to_string()))
+        );
+        assert_eq!(checker.check(&"18".into()), Err(CheckError::InvalidKind));
+    }
+
+    #[test]
+    fn test_less_than() {
+        let checker = Checkers::LessThan(100.0, "too large".to_string());
+        assert_eq!(checker.check(&99.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&100.into()),
+            Ok(Notice::Attention("too large".to_string()))
+        );
+        assert_eq!(checker.check(&"99".into()), Err(CheckError::InvalidKind));
+    }
+
+    #[test]
+    fn test_less_or_equal() {
+        let checker = Checkers::LessOrEqual(100.0, "too large".to_string());
+        assert_eq!(checker.check(&100.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&100.1.into()),
+            Ok(Notice::Attention("too large".to_string()))
+        );
+        assert_eq!(checker.check(&"100".into()), Err(CheckError::InvalidKind));
+    }
+
+    #[test]
+    fn test_one_of() {
+        let checker = Checkers::OneOf(
+            vec!["open".to_string(), "closed".to_string(), "42".to_string()],
+            "unknown status".to_string(),
+        );
+        assert_eq!(checker.check(&"open".into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&42.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"pending".into()),
+            Ok(Notice::Attention("unknown status".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_length() {
+        let checker = Checkers::Length {
+            min: Some(1),
+            max: Some(3),
+            msg: "bad length".to_string(),
+        };
+        assert_eq!(
+            checker.check(&"".into()),
+            Ok(Notice::Attention("bad length".to_string()))
+        );
+        assert_eq!(checker.check(&"あいう".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"abcd".into()),
+            Ok(Notice::Attention("bad length".to_string()))
+        );
+
+        let unbounded = Checkers::Length {
+            min: None,
+            max: None,
+            msg: "bad length".to_string(),
+        };
+        assert_eq!(unbounded.check(&"".into()), Ok(Notice::Clear));
+    }
+
+    #[test]
+    fn test_boolean() {
+        let is_true = Checkers::IsTrue("must be checked".to_string());
+        assert_eq!(is_true.check(&true.into()), Ok(Notice::Clear));
+        assert_eq!(
+            is_true.check(&false.into()),
+            Ok(Notice::Attention("must be checked".to_string()))
+        );
+        assert_eq!(is_true.check(&"true".into()), Err(CheckError::InvalidKind));
+
+        let is_false = Checkers::IsFalse("must be unchecked".to_string());
+        assert_eq!(is_false.check(&false.into()), Ok(Notice::Clear));
+        assert_eq!(
+            is_false.check(&true.into()),
+            Ok(Notice::Attention("must be unchecked".to_string()))
+        );
+        assert_eq!(is_false.check(&1.into()), Err(CheckError::InvalidKind));
+
+        assert_eq!(Checkers::Any.check(&true.into()), Ok(Notice::Clear));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_notice_serde() {
+        let notice = Notice::Attention("x".to_string());
+        let json = serde_json::to_string(&notice).unwrap();
+        assert_eq!(json, r#"{"type":"attention","message":"x"}"#);
+        assert_eq!(serde_json::from_str::<Notice>(&json).unwrap(), notice);
+        assert_eq!(
+            serde_json::from_str::<Notice>(r#"{"type":"clear"}"#).unwrap(),
+            Notice::Clear
+        );
+
+        let err = serde_json::from_str::<Notice>(r#"{"type":"warning","message":"x"}"#)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("unknown variant `warning`"));
+
+        let coded = Notice::Error("taken".to_string()).with_code("E042");
+        let json = serde_json::to_string(&coded).unwrap();
+        assert_eq!(json, r#"{"type":"error","code":"E042","message":"taken"}"#);
+        assert_eq!(serde_json::from_str::<Notice>(&json).unwrap(), coded);
+        assert!(
+            serde_json::from_str::<Notice>(r#"{"type":"info","code":"I1","message":"x"}"#).is_err()
+        );
+        assert!(serde_json::from_str::<Notice>(r#"{"type":"error"}"#).is_err());
+    }
+
+    #[test]
+    fn test_and() {
+        let checker = Checkers::And(
+            vec![
+                Box::new(Checkers::Between(0.0, 100.0, "out of range".to_string()).into_error()),
+                Box::new(Checkers::GreaterThan(10.0, "too small".to_string())),
+            ],
+            "invalid".to_string(),
+        );
+        assert_eq!(checker.check(&50.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&5.into()),
+            Ok(Notice::Attention("invalid".to_string()))
+        );
+        assert_eq!(
+            checker.check(&(-5).into()),
+            Ok(Notice::Error("invalid".to_string()))
+        );
+        assert_eq!(checker.check(&"50".into()), Err(CheckError::InvalidKind));
+    }
+
+    #[test]
+    fn test_or() {
+        let checker = Checkers::Or(
+            vec![
+                Box::new(Checkers::Between(0.0, 10.0, "out of range".to_string())),
+                Box::new(Checkers::GreaterThan(100.0, "too small".to_string())),
+            ],
+            "invalid".to_string(),
+        );
+        assert_eq!(checker.check(&5.into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&200.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&50.into()),
+            Ok(Notice::Attention("invalid".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_combinator_kind_mismatch() {
+        let checker = Checkers::And(
+            vec![
+                Box::new(Checkers::Between(0.0, 10.0, "out of range".to_string())),
+                Box::new(Checkers::Exact("5".to_string(), "not 5".to_string())),
+            ],
+            "invalid".to_string(),
+        );
+        assert_eq!(checker.check(&5.into()), Err(CheckError::InvalidKind));
+    }
+
+    #[test]
+    fn test_not() {
+        let checker = Checkers::Not(
+            Box::new(Checkers::Exact("forbidden".to_string(), "".to_string())),
+            "forbidden word".to_string(),
+        );
+        assert_eq!(
+            checker.check(&"forbidden".into()),
+            Ok(Notice::Attention("forbidden word".to_string()))
+        );
+        assert_eq!(checker.check(&"allowed".into()), Ok(Notice::Clear));
+    }
+
+    #[test]
+    fn test_flatten_tie_break() {
+        let make = |order: [(&str, &str); 2]| {
+            order
+                .into_iter()
+                .map(|(v, msg)| Checkers::Exact(v.to_string(), msg.to_string()))
+                .into_flat()
+                .unwrap()
+        };
+        let forward = make([("x", "first"), ("y", "second")]);
+        let backward = make([("y", "second"), ("x", "first")]);
+        assert_eq!(
+            forward.check(&"z".into()),
+            Ok(Notice::Attention("second".to_string()))
+        );
+        assert_eq!(
+            backward.check(&"z".into()),
+            Ok(Notice::Attention("second".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_not_empty() {
+        let checker = Checkers::NotEmpty("required".to_string());
+        assert_eq!(
+            checker.check(&"".into()),
+            Ok(Notice::Attention("required".to_string()))
+        );
+        assert_eq!(
+            checker.check(&"   ".into()),
+            Ok(Notice::Attention("required".to_string()))
+        );
+        assert_eq!(checker.check(&"ok".into()), Ok(Notice::Clear));
+    }
+
+    #[test]
+    fn test_flatten_kind_guard() {
+        let between = vec![Checkers::Between(0.0, 1.0, "out of range".to_string())]
+            .into_iter()
+            .into_flat()
+            .unwrap();
+        assert_eq!(between.check(&"abc".into()), Err(CheckError::InvalidKind));
+
+        let exact = vec![Checkers::Exact("true".to_string(), "not true".to_string())]
+            .into_iter()
+            .into_flat()
+            .unwrap();
+        assert_eq!(exact.check(&true.into()), Err(CheckError::InvalidKind));
+        assert_eq!(exact.check(&"true".into()), Ok(Notice::Clear));
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let checker = Checkers::from_fn(KindSet::NUMBER, |v| {
+            let n = f64::try_from(v).map_err(CheckError::Parse)?;
+            Ok(match n % 2.0 == 0.0 {
+                true => Notice::Clear,
+                false => Notice::Attention("must be even".to_string()),
+            })
+        });
+        assert_eq!(checker.expecting(), KindSet::NUMBER);
+        assert_eq!(checker.check(&4.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&3.into()),
+            Ok(Notice::Attention("must be even".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_contains() {
+        let checker = Checkers::Contains("@".to_string(), "missing @".to_string());
+        assert_eq!(checker.check(&"a@b.com".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"ab.com".into()),
+            Ok(Notice::Attention("missing @".to_string()))
+        );
+
+        let checker = Checkers::Contains("Foo".to_string(), "missing Foo".to_string());
+        assert_eq!(
+            checker.check(&"a foo b".into()),
+            Ok(Notice::Attention("missing Foo".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_contains_ignore_case() {
+        let checker = Checkers::ContainsIgnoreCase("Foo".to_string(), "missing Foo".to_string());
+        assert_eq!(checker.check(&"a FOO b".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"a bar b".into()),
+            Ok(Notice::Attention("missing Foo".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_starts_with() {
+        let checker = Checkers::StartsWith("id_".to_string(), "bad prefix".to_string());
+        assert_eq!(checker.check(&"id_42".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"42_id".into()),
+            Ok(Notice::Attention("bad prefix".to_string()))
+        );
+
+        let empty = Checkers::StartsWith("".to_string(), "bad prefix".to_string());
+        assert_eq!(empty.check(&"anything".into()), Ok(Notice::Clear));
+        assert_eq!(empty.check(&"".into()), Ok(Notice::Clear));
+    }
+
+    #[test]
+    fn test_ends_with() {
+        let checker = Checkers::EndsWith(".csv".to_string(), "bad suffix".to_string());
+        assert_eq!(checker.check(&"data.csv".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"data.tsv".into()),
+            Ok(Notice::Attention("bad suffix".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_info_ordering() {
+        let clear = Notice::Clear;
+        let info = Notice::Info("z".to_string());
+        let attention = Notice::Attention("a".to_string());
+        let error = Notice::Error("a".to_string());
+        assert!(clear < info);
+        assert!(info < attention);
+        assert!(attention < error);
+        assert!(Notice::Info("a".to_string()) < info);
+        assert_eq!(
+            Result::<Notice, String>::from(Notice::Info("fyi".to_string())),
+            Ok(Notice::Info("fyi".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_info_mode() {
+        let info = || {
+            Checkers::from_fn(KindSet::NUMBER | KindSet::LITERAL, |_| {
+                Ok(Notice::Info("fyi".to_string()))
+            })
+        };
+        assert_eq!(
+            info().into_attention().check(&"a".into()),
+            Ok(Notice::Info("fyi".to_string()))
+        );
+        assert_eq!(
+            info().into_error().check(&"a".into()),
+            Ok(Notice::Error("fyi".to_string()))
+        );
+
+        let flatten = vec![
+            info(),
+            Checkers::Exact("a".to_string(), "not a".to_string()),
+        ]
+        .into_iter()
+        .into_flat()
+        .unwrap();
+        assert_eq!(
+            flatten.check(&"a".into()),
+            Ok(Notice::Info("fyi".to_string()))
+        );
+        assert_eq!(
+            flatten.check(&"b".into()),
+            Ok(Notice::Attention("not a".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_severity() {
+        let notices = [
+            Notice::Clear,
+            Notice::Info("i".to_string()),
+            Notice::Attention("a".to_string()),
+            Notice::Error("e".to_string()),
+        ];
+        assert_eq!(
+            notices.iter().map(|n| n.severity()).collect::<Vec<_>>(),
+            vec![0, 1, 2, 3]
+        );
+        assert_eq!(
+            notices.iter().map(|n| n.is_clear()).collect::<Vec<_>>(),
+            vec![true, false, false, false]
+        );
+        assert_eq!(
+            notices.iter().map(|n| n.is_error()).collect::<Vec<_>>(),
+            vec![false, false, false, true]
+        );
+        for (a, b) in notices.iter().tuple_windows() {
+            assert!(a < b && a.severity() < b.severity());
+        }
+    }
+
+    #[test]
+    fn test_flatten_empty() {
+        let res = Vec::<Checkers>::new().into_iter().into_flat();
+        assert_eq!(res.err(), Some(FlattenError::Empty));
+    }
+
+    #[test]
+    fn test_regex_message_template() {
+        let checker = Checkers::Regex(
+            regex::Regex::new("^[0-9]+$").unwrap(),
+            "expected {pattern}, got {value}".to_string(),
+        );
+        assert_eq!(checker.check(&"123".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"abc".into()),
+            Ok(Notice::Attention("expected ^[0-9]+$, got abc".to_string()))
+        );
+
+        let plain = Checkers::Regex(
+            regex::Regex::new("^[0-9]+$").unwrap(),
+            "digits only".to_string(),
+        );
+        assert_eq!(
+            plain.check(&"abc".into()),
+            Ok(Notice::Attention("digits only".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_exact_ignore_case() {
+        let checker = Checkers::ExactIgnoreCase("US".to_string(), "wrong country".to_string());
+        assert_eq!(checker.check(&"us".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"ca".into()),
+            Ok(Notice::Attention("wrong country".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_between_opt() {
+        let checker = Checkers::BetweenOpt {
+            min: Some(0.0),
+            max: None,
+            msg: "negative".to_string(),
+        };
+        assert_eq!(checker.check(&0.into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&1e300.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&(-0.5).into()),
+            Ok(Notice::Attention("negative".to_string()))
+        );
+        assert_eq!(checker.check(&"1".into()), Err(CheckError::InvalidKind));
+
+        let checker = Checkers::BetweenOpt {
+            min: None,
+            max: Some(10.0),
+            msg: "too large".to_string(),
+        };
+        assert_eq!(checker.check(&(-1e300).into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&11.into()),
+            Ok(Notice::Attention("too large".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_describe() {
+        assert_eq!(
+            Checkers::Between(-2.0, 2.0, "caution".to_string()).describe(),
+            "between -2 and 2"
+        );
+        assert_eq!(
+            Checkers::Exact("abc".to_string(), "caution".to_string()).describe(),
+            "exactly \"abc\""
+        );
+        assert_eq!(
+            Checkers::from_fn(KindSet::NUMBER, |_| Ok(Notice::Clear)).describe(),
+            "custom check"
+        );
+
+        let flatten = vec![
+            Checkers::Between(-2.0, 2.0, "caution".to_string()).into_attention(),
+            Checkers::GreaterOrEqual(0.0, "error".to_string()).into_error(),
+        ]
+        .into_iter()
+        .into_flat()
+        .unwrap();
+        assert_eq!(flatten.describe(), "between -2 and 2, at least 0");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_date_between() {
+        use chrono::NaiveDate;
+
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+        let checker = Checkers::DateBetween(
+            date(2024, 1, 1),
+            date(2024, 12, 31),
+            "out of range".to_string(),
+        );
+        assert_eq!(checker.check(&date(2024, 1, 1).into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&date(2024, 6, 15).into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&date(2025, 1, 1).into()),
+            Ok(Notice::Attention("out of range".to_string()))
+        );
+        assert_eq!(
+            checker.check(&"2024-06-15".into()),
+            Err(CheckError::InvalidKind)
+        );
+    }
+
+    #[test]
+    fn test_multiple_of() {
+        let checker = Checkers::MultipleOf(5.0, "not a multiple of 5".to_string());
+        assert_eq!(checker.check(&10.into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&(-15).into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&7.into()),
+            Ok(Notice::Attention("not a multiple of 5".to_string()))
+        );
+
+        let checker = Checkers::MultipleOf(0.1, "not a multiple of 0.1".to_string());
+        assert_eq!(checker.check(&0.3.into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&1e12.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&1000000000000.05.into()),
+            Ok(Notice::Attention("not a multiple of 0.1".to_string()))
+        );
+
+        let checker = Checkers::MultipleOf(1e-12, "not a multiple of 1e-12".to_string());
+        assert_eq!(checker.check(&3e-12.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&1.5e-12.into()),
+            Ok(Notice::Attention("not a multiple of 1e-12".to_string()))
+        );
+
+        let checker = Checkers::MultipleOf(0.0, "never".to_string());
+        assert!(matches!(
+            checker.check(&10.into()),
+            Err(CheckError::InvalidRule(_))
+        ));
+    }
+
+    #[test]
+    fn test_is_integer() {
+        let checker = Checkers::IsInteger("must be whole".to_string());
+        assert_eq!(checker.check(&3.into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&3.0.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&3.5.into()),
+            Ok(Notice::Attention("must be whole".to_string()))
+        );
+        assert_eq!(checker.check(&"3".into()), Err(CheckError::InvalidKind));
+    }
+
+    #[cfg(feature = "parallel")]
+    #[test]
+    fn test_check_par() {
+        struct Threshold(f64, String);
+
+        impl Checker for Threshold {
+            fn check(&self, value: &crate::value::Value) -> Result<Notice, CheckError> {
+                let v = f64::try_from(value).map_err(CheckError::Parse)?;
+                Ok(match v < self.0 {
+                    true => Notice::Clear,
+                    false => Notice::Attention(self.1.to_string()),
+                })
+            }
+
+            fn expecting(&self) -> KindSet {
+                KindSet::NUMBER
+            }
+        }
+
+        let flatten = (0..100)
+            .map(|i| Threshold(i as f64, format!("at least {:03}", i)))
+            .into_flat()
+            .unwrap();
+        for v in [-1, 0, 42, 99, 100] {
+            assert_eq!(flatten.check_par(&v.into()), flatten.check(&v.into()));
+        }
+        assert_eq!(
+            flatten.check_par(&42.into()),
+            Ok(Notice::Attention("at least 042".to_string()))
+        );
+
+        struct SameAs(&'static str);
+
+        impl Checker for SameAs {
+            fn check(&self, value: &crate::value::Value) -> Result<Notice, CheckError> {
+                self.check_with_context(value, &HashMap::new())
+            }
+
+            fn check_with_context(
+                &self,
+                value: &crate::value::Value,
+                context: &HashMap<String, Value>,
+            ) -> Result<Notice, CheckError> {
+                let other = context
+                    .get(self.0)
+                    .ok_or_else(|| CheckError::MissingField(self.0.to_string()))?;
+                Ok(match other == value {
+                    true => Notice::Clear,
+                    false => Notice::Attention("mismatch".to_string()),
+                })
+            }
+
+            fn expecting(&self) -> KindSet {
+                KindSet::LITERAL
+            }
+        }
+
+        let flatten = vec![SameAs("password")].into_iter().into_flat().unwrap();
+        let context = HashMap::from([("password".to_string(), "hunter2".into())]);
+        assert_eq!(
+            flatten.check_par_with_context(&"hunter2".into(), &context),
+            Ok(Notice::Clear)
+        );
+        assert_eq!(
+            flatten.check_par(&"hunter2".into()),
+            Err(CheckError::MissingField("password".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_flatten_short_circuit() {
+        use std::{cell::Cell, rc::Rc};
+
+        struct Spy(Rc<Cell<usize>>, Notice);
+
+        impl Checker for Spy {
+            fn check(&self, _: &crate::value::Value) -> Result<Notice, CheckError> {
+                self.0.set(self.0.get() + 1);
+                Ok(self.1.clone())
+            }
+
+            fn expecting(&self) -> KindSet {
+                KindSet::LITERAL
+            }
+        }
+
+        let calls = Rc::new(Cell::new(0));
+        let flatten = vec![
+            Spy(calls.clone(), Notice::Attention("attention".to_string())),
+            Spy(calls.clone(), Notice::Error("error".to_string())),
+            Spy(calls.clone(), Notice::Clear),
+            Spy(calls.clone(), Notice::Error("later".to_string())),
+        ]
+        .into_iter()
+        .into_flat()
+        .unwrap();
+        assert_eq!(
+            flatten.check(&"a".into()),
+            Ok(Notice::Error("error".to_string()))
+        );
+        assert_eq!(calls.get(), 2);
+    }
+
+    #[test]
+    fn test_flatten_first_error() {
+        let make = |order: [(&str, &str); 2]| {
+            order
+                .into_iter()
+                .map(|(v, msg)| Checkers::Exact(v.to_string(), msg.to_string()).into_error())
+                .into_flat()
+                .unwrap()
+        };
+        let forward = make([("x", "first"), ("y", "second")]);
+        let backward = make([("y", "second"), ("x", "first")]);
+        assert_eq!(
+            forward.check(&"z".into()),
+            Ok(Notice::Error("first".to_string()))
+        );
+        assert_eq!(
+            backward.check(&"z".into()),
+            Ok(Notice::Error("second".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_flatten_merge() {
+        let a = vec![Checkers::Between(-2.0, 2.0, "caution".to_string())]
+            .into_iter()
+            .into_flat()
+            .unwrap();
+        let b = vec![Checkers::LessThan(1.0, "too large".to_string())]
+            .into_iter()
+            .into_flat()
+            .unwrap();
+        let merged = a.merge(b).unwrap();
+        assert_eq!(merged.rules().len(), 2);
+        assert_eq!(
+            merged.check(&1.5.into()),
+            Ok(Notice::Attention("too large".to_string()))
+        );
+
+        let c = vec![Checkers::NotEmpty("required".to_string())]
+            .into_iter()
+            .into_flat()
+            .unwrap();
+        assert_eq!(merged.merge(c).err(), Some(FlattenError::InvalidKind));
+    }
+
+    #[test]
+    fn test_in_ranges() {
+        let checker = Checkers::InRanges(
+            vec![(200.0, 299.0), (400.0, 499.0)],
+            "unexpected status".to_string(),
+        );
+        assert_eq!(checker.check(&404.into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&200.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&300.into()),
+            Ok(Notice::Attention("unexpected status".to_string()))
+        );
+        assert_eq!(
+            checker.describe(),
+            "between 200 and 299 or between 400 and 499"
+        );
+    }
+
+    #[test]
+    fn test_notice_display() {
+        assert_eq!(Notice::Clear.to_string(), "OK");
+        assert_eq!(Notice::Info("fyi".to_string()).to_string(), "fyi");
+        assert_eq!(
+            Notice::Attention("caution".to_string()).to_string(),
+            "caution"
+        );
+        assert_eq!(Notice::Error("error".to_string()).to_string(), "error");
+    }
+
+    #[test]
+    fn test_optional() {
+        let checker = Checkers::Optional(Box::new(Checkers::StartsWith(
+            "+81".to_string(),
+            "bad phone".to_string(),
+        )));
+        assert_eq!(checker.check(&"".into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&"+81-3-1234".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"03-1234".into()),
+            Ok(Notice::Attention("bad phone".to_string()))
+        );
+        assert_eq!(checker.check(&Value::null()), Ok(Notice::Clear));
+        assert_eq!(checker.expecting(), KindSet::LITERAL | KindSet::NULL);
+    }
+
+    #[test]
+    fn test_is_numeric() {
+        let checker = Checkers::IsNumeric("not a number".to_string());
+        assert_eq!(checker.check(&"3.14".into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&2.5.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"abc".into()),
+            Ok(Notice::Attention("not a number".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_regex_str() {
+        let checker = Checkers::regex_str("^[a-z]+$", "lowercase only").unwrap();
+        assert_eq!(checker.check(&"abc".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"ABC".into()),
+            Ok(Notice::Attention("lowercase only".to_string()))
+        );
+
+        assert!(Checkers::regex_str("([a-z]+", "unbalanced").is_err());
+    }
+
+    #[test]
+    fn test_max_decimals() {
+        let checker = Checkers::MaxDecimals(2, "too precise".to_string());
+        assert_eq!(checker.check(&1.99.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&1.999.into()),
+            Ok(Notice::Attention("too precise".to_string()))
+        );
+        assert_eq!(checker.check(&1.into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&"1.99".into()), Err(CheckError::InvalidKind));
+    }
+
+    #[test]
+    fn test_email() {
+        let checker = Checkers::Email("invalid email".to_string());
+        assert_eq!(checker.check(&"a@b.com".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"first.last@mail.example.co.jp".into()),
+            Ok(Notice::Clear)
+        );
+        for invalid in ["a@b", "no-at-sign", "a@@b.com", "a b@c.com", "a@b..com"] {
+            assert_eq!(
+                checker.check(&invalid.into()),
+                Ok(Notice::Attention("invalid email".to_string())),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[cfg(feature = "url")]
+    #[test]
+    fn test_url() {
+        let checker = Checkers::Url("invalid url".to_string());
+        assert_eq!(
+            checker.check(&"https://example.com".into()),
+            Ok(Notice::Clear)
+        );
+        assert_eq!(
+            checker.check(&"http://example.com/path?q=1".into()),
+            Ok(Notice::Clear)
+        );
+        for invalid in [
+            "example.com",
+            "/relative/path",
+            "ftp://example.com",
+            "mailto:a@b.com",
+        ] {
+            assert_eq!(
+                checker.check(&invalid.into()),
+                Ok(Notice::Attention("invalid url".to_string())),
+                "{}",
+                invalid
+            );
+        }
+    }
+
+    #[test]
+    fn test_in_set() {
+        let skus = (0..1000).map(|i| format!("SKU-{:04}", i)).collect();
+        let checker = Checkers::InSet(skus, "unknown sku".to_string());
+        assert_eq!(checker.check(&"SKU-0999".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"SKU-1000".into()),
+            Ok(Notice::Attention("unknown sku".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_normalized() {
+        let checker = Checkers::Normalized {
+            transform: NormalizeKind::Trim,
+            inner: Box::new(Checkers::Exact("abc".to_string(), "not abc".to_string())),
+        };
+        assert_eq!(checker.check(&"  abc \n".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&" abd ".into()),
+            Ok(Notice::Attention("not abc".to_string()))
+        );
+
+        let checker = Checkers::Normalized {
+            transform: NormalizeKind::Uppercase,
+            inner: Box::new(Checkers::Exact("JP".to_string(), "not JP".to_string())),
+        };
+        assert_eq!(checker.check(&"jp".into()), Ok(Notice::Clear));
+        assert_eq!(checker.expecting(), KindSet::NUMBER | KindSet::LITERAL);
+    }
+
+    #[test]
+    fn test_each() {
+        let checker = Checkers::Each(
+            Box::new(Checkers::Between(0.0, 10.0, "out of range".to_string())),
+            "invalid element".to_string(),
+        );
+        assert_eq!(checker.check(&Value::number("1, 5,10")), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&Value::number("1,12,3")),
+            Ok(Notice::Attention("invalid element".to_string()))
+        );
+        assert!(matches!(
+            checker.check(&Value::number("1,x")),
+            Err(CheckError::Parse(_))
+        ));
+        assert_eq!(checker.expecting(), KindSet::NUMBER);
+    }
+
+    #[test]
+    fn test_required() {
+        let checker = Checkers::Required("required".to_string());
+        assert_eq!(
+            checker.check(&Value::null()),
+            Ok(Notice::Attention("required".to_string()))
+        );
+        assert_eq!(checker.check(&"".into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&0.into()), Ok(Notice::Clear));
+    }
+
+    #[test]
+    fn test_flatten_null_accepting() {
+        let flat = vec![
+            Checkers::Required("required".to_string()),
+            Checkers::NotEmpty("empty".to_string()),
+        ]
+        .into_iter()
+        .into_flat()
+        .unwrap();
+        assert_eq!(flat.expecting(), KindSet::LITERAL | KindSet::NULL);
+        assert_eq!(
+            flat.check(&Value::null()),
+            Ok(Notice::Attention("required".to_string()))
+        );
+        assert_eq!(
+            flat.check(&" ".into()),
+            Ok(Notice::Attention("empty".to_string()))
+        );
+        assert_eq!(flat.check(&1.into()), Err(CheckError::InvalidKind));
+
+        let flat = vec![
+            Checkers::Optional(Box::new(Checkers::Between(
+                0.0,
+                1.0,
+                "out of range".to_string(),
+            ))),
+            Checkers::NotEmpty("empty".to_string()),
+        ]
+        .into_iter()
+        .into_flat();
+        assert!(matches!(flat, Err(FlattenError::InvalidKind)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_valid_json() {
+        let checker = Checkers::ValidJson("invalid json".to_string());
+        assert_eq!(checker.check(&r#"{"a":1}"#.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"{a:1}".into()),
+            Ok(Notice::Attention("invalid json".to_string()))
+        );
+        assert_eq!(checker.expecting(), KindSet::LITERAL);
+    }
+
+    #[test]
+    fn test_any_regex() {
+        let checker = Checkers::AnyRegex(
+            vec![
+                regex::Regex::new(r"^\d{3}-\d{4}$").unwrap(),
+                regex::Regex::new(r"^\d{7}$").unwrap(),
+            ],
+            "invalid postal code".to_string(),
+        );
+        assert_eq!(checker.check(&"1234567".into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&"123-4567".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"12-34567".into()),
+            Ok(Notice::Attention("invalid postal code".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_try_clone() {
+        let checker = Checkers::Between(0.0, 10.0, "out of range".to_string());
+        let cloned = checker.try_clone().unwrap();
+        for c in [&checker, &cloned] {
+            assert_eq!(c.check(&5.into()), Ok(Notice::Clear));
+            assert_eq!(
+                c.check(&11.into()),
+                Ok(Notice::Attention("out of range".to_string()))
+            );
+        }
+
+        let modes = vec![checker.into_error(), cloned.into_attention()]
+            .into_iter()
+            .into_flat();
+        assert!(modes.is_ok());
+
+        let custom = Checkers::from_fn(KindSet::LITERAL, |_| Ok(Notice::Clear));
+        assert!(custom.try_clone().is_none());
+    }
+
+    #[test]
+    fn test_unique_checker() {
+        let checker = UniqueChecker::new("duplicated");
+        let notices = ["a", "b", "a"]
+            .into_iter()
+            .map(|v| checker.check(&v.into()))
+            .collect_vec();
+        assert_eq!(
+            notices,
+            vec![
+                Ok(Notice::Clear),
+                Ok(Notice::Clear),
+                Ok(Notice::Attention("duplicated".to_string()))
+            ]
+        );
+    }
+
+    #[test]
+    fn test_into_soft() {
+        let checker = Checkers::Between(0.0, 10.0, "out of range".to_string())
+            .into_error()
+            .into_soft();
+        assert_eq!(checker.check(&5.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&11.into()),
+            Ok(Notice::Attention("out of range".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_with_message() {
+        let checker = WithMessage(
+            Checkers::Exact("abc".to_string(), "caution".to_string()).into_error(),
+            "must be abc".to_string(),
+        );
+        assert_eq!(checker.check(&"abc".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"abd".into()),
+            Ok(Notice::Error("must be abc".to_string()))
+        );
+        assert_eq!(checker.expecting(), KindSet::NUMBER | KindSet::LITERAL);
+    }
+
+    #[test]
+    fn test_percentage() {
+        let checker = Checkers::Percentage("invalid percentage".to_string());
+        assert_eq!(checker.check(&"85%".into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&"100".into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&0.into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"150%".into()),
+            Ok(Notice::Attention("invalid percentage".to_string()))
+        );
+        assert_eq!(
+            checker.check(&"abc".into()),
+            Ok(Notice::Attention("invalid percentage".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_check_all() {
+        let flatten = vec![
+            Checkers::GreaterThan(10.0, "too small".to_string()).into_attention(),
+            Checkers::MultipleOf(2.0, "not even".to_string()).into_error(),
+            Checkers::Between(20.0, 30.0, "out of range".to_string()).into_attention(),
+            Checkers::LessThan(100.0, "too large".to_string()).into_attention(),
+        ]
+        .into_iter()
+        .into_flat()
+        .unwrap();
+        assert_eq!(
+            flatten.check_all(&5.into()),
+            Ok(vec![
+                Notice::Attention("too small".to_string()),
+                Notice::Error("not even".to_string()),
+                Notice::Attention("out of range".to_string()),
+            ])
+        );
+        assert_eq!(flatten.check_all(&24.into()), Ok(vec![]));
+        assert_eq!(
+            flatten.check_all(&"abc".into()),
+            Err(CheckError::InvalidKind)
+        );
+
+        let flatten = vec![
+            Checkers::EqualsField("password".to_string(), "mismatch".to_string()),
+            Checkers::regex_str("^hunter", "weak").unwrap(),
+        ]
+        .into_iter()
+        .into_flat()
+        .unwrap();
+        let context = HashMap::from([("password".to_string(), "hunter2".into())]);
+        assert_eq!(
+            flatten.check_all_with_context(&"hunter3".into(), &context),
+            Ok(vec![Notice::Attention("mismatch".to_string())])
+        );
+        assert_eq!(
+            flatten.check_all_with_context(&"hunter2".into(), &context),
+            Ok(vec![])
+        );
+    }
+
+    #[test]
+    fn test_one_of_ignore_case() {
+        let checker = Checkers::OneOfIgnoreCase(
+            vec!["open".to_string(), "closed".to_string()],
+            "unknown status".to_string(),
+        );
+        assert_eq!(checker.check(&"Open".into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&"CLOSED".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"banana".into()),
+            Ok(Notice::Attention("unknown status".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_coded() {
+        let checker = Coded(
+            Checkers::Between(0.0, 10.0, "out of range".to_string()),
+            "E_RANGE".to_string(),
+        );
+        assert_eq!(checker.check(&5.into()), Ok(Notice::Clear));
+        let notice = checker.check(&11.into()).unwrap();
+        assert_eq!(notice.code(), Some("E_RANGE"));
+        assert_eq!(notice.message(), Some("out of range"));
+        assert_eq!(notice.severity(), 2);
+
+        let notice = checker.into_error().check(&11.into()).unwrap();
+        assert_eq!(
+            notice,
+            Notice::ErrorCoded {
+                code: "E_RANGE".to_string(),
+                message: "out of range".to_string(),
+            }
+        );
+        assert!(notice > Notice::Attention("out of range".to_string()));
+        assert!(notice.is_error());
+
+        let flatten = vec![
+            Box::new(Coded(
+                Checkers::GreaterThan(0.0, "negative".to_string()),
+                "E_SIGN".to_string(),
+            )) as Box<dyn Checker>,
+            Box::new(Checkers::LessThan(10.0, "too large".to_string()).into_error()),
+        ]
+        .into_iter()
+        .into_flat()
+        .unwrap();
+        assert_eq!(flatten.check(&(-1).into()).unwrap().code(), Some("E_SIGN"));
+    }
+
+    #[test]
+    fn test_uuid() {
+        let checker = Checkers::Uuid("invalid uuid".to_string());
+        assert_eq!(
+            checker.check(&"123e4567-e89b-12d3-A456-426614174000".into()),
+            Ok(Notice::Clear)
+        );
+        assert_eq!(
+            checker.check(&"123e4567-e89b-12d3-a456-42661417400g".into()),
+            Ok(Notice::Attention("invalid uuid".to_string()))
+        );
+        assert_eq!(
+            checker.check(&"123e4567-e89b-12d3-a456".into()),
+            Ok(Notice::Attention("invalid uuid".to_string()))
+        );
+        assert_eq!(
+            checker.check(&"123e4567fe89b-12d3-a456-426614174000".into()),
+            Ok(Notice::Attention("invalid uuid".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_length_in() {
+        let value = "👍🏽ok".into();
+        let checker = |unit| Checkers::LengthIn {
+            unit,
+            min: None,
+            max: Some(4),
+            msg: "too long".to_string(),
+        };
+        assert_eq!(checker(LengthUnit::Chars).check(&value), Ok(Notice::Clear));
+        assert_eq!(
+            checker(LengthUnit::Bytes).check(&value),
+            Ok(Notice::Attention("too long".to_string()))
+        );
+        assert_eq!(LengthUnit::Chars.count("👍🏽ok"), 4);
+        assert_eq!(LengthUnit::Bytes.count("👍🏽ok"), 10);
+    }
+
+    #[cfg(feature = "unicode-segmentation")]
+    #[test]
+    fn test_length_in_graphemes() {
+        assert_eq!(LengthUnit::GraphemeClusters.count("👍🏽ok"), 3);
+        let checker = Checkers::LengthIn {
+            unit: LengthUnit::GraphemeClusters,
+            min: Some(3),
+            max: Some(3),
+            msg: "invalid length".to_string(),
+        };
+        assert_eq!(checker.check(&"👍🏽ok".into()), Ok(Notice::Clear));
+    }
+
+    #[test]
+    fn test_memoized() {
+        use std::{cell::Cell, rc::Rc};
+
+        let calls = Rc::new(Cell::new(0));
+        let counter = calls.clone();
+        let checker = Memoized::new(Checkers::from_fn(KindSet::LITERAL, move |v| {
+            counter.set(counter.get() + 1);
+            Ok(match v.to_string().len() > 3 {
+                true => Notice::Attention("too long".to_string()),
+                false => Notice::Clear,
+            })
+        }));
+        assert_eq!(checker.check(&"abcd".into()), checker.check(&"abcd".into()));
+        assert_eq!(calls.get(), 1);
+        assert_eq!(checker.check(&"abc".into()), Ok(Notice::Clear));
+        assert_eq!(calls.get(), 2);
+
+        let context = HashMap::from([("other".to_string(), "x".into())]);
+        assert_eq!(
+            checker.check_with_context(&"abc".into(), &context),
+            Ok(Notice::Clear)
+        );
+        assert_eq!(calls.get(), 2);
+
+        let confirm = Memoized::new(Checkers::EqualsField(
+            "password".to_string(),
+            "mismatch".to_string(),
+        ));
+        assert!(confirm.uses_context());
+        let context = HashMap::from([("password".to_string(), "a".into())]);
+        assert_eq!(
+            confirm.check_with_context(&"a".into(), &context),
+            Ok(Notice::Clear)
+        );
+        let context = HashMap::from([("password".to_string(), "b".into())]);
+        assert_eq!(
+            confirm.check_with_context(&"a".into(), &context),
+            Ok(Notice::Attention("mismatch".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_sign() {
+        let positive = Checkers::Positive("not positive".to_string());
+        let negative = Checkers::Negative("not negative".to_string());
+        let non_zero = Checkers::NonZero("zero".to_string());
+        let attention = |msg: &str| Ok(Notice::Attention(msg.to_string()));
+
+        assert_eq!(positive.check(&0.into()), attention("not positive"));
+        assert_eq!(positive.check(&5.into()), Ok(Notice::Clear));
+        assert_eq!(positive.check(&(-5).into()), attention("not positive"));
+
+        assert_eq!(negative.check(&0.into()), attention("not negative"));
+        assert_eq!(negative.check(&5.into()), attention("not negative"));
+        assert_eq!(negative.check(&(-5).into()), Ok(Notice::Clear));
+
+        assert_eq!(non_zero.check(&0.into()), attention("zero"));
+        assert_eq!(non_zero.check(&5.into()), Ok(Notice::Clear));
+        assert_eq!(non_zero.check(&(-5).into()), Ok(Notice::Clear));
+    }
+
+    #[test]
+    fn test_iso_codes() {
+        let country = Checkers::CountryCode("unknown country".to_string());
+        assert_eq!(country.check(&"US".into()), Ok(Notice::Clear));
+        assert_eq!(country.check(&"us".into()), Ok(Notice::Clear));
+        assert_eq!(
+            country.check(&"ZZ".into()),
+            Ok(Notice::Attention("unknown country".to_string()))
+        );
+
+        let currency = Checkers::CurrencyCode("unknown currency".to_string());
+        assert_eq!(currency.check(&"USD".into()), Ok(Notice::Clear));
+        assert_eq!(currency.check(&"jpy".into()), Ok(Notice::Clear));
+        assert_eq!(
+            currency.check(&"XXX".into()),
+            Ok(Notice::Attention("unknown currency".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_iso_codes_sorted() {
+        assert!(crate::iso::COUNTRY_CODES.windows(2).all(|w| w[0] < w[1]));
+        assert!(crate::iso::CURRENCY_CODES.windows(2).all(|w| w[0] < w[1]));
+    }
+
+    #[test]
+    fn test_regex_full() {
+        let full = Checkers::regex_full("[a-z]+", "invalid").unwrap();
+        let partial = Checkers::regex_str("[a-z]+", "invalid").unwrap();
+        assert_eq!(
+            full.check(&"abc123".into()),
+            Ok(Notice::Attention("invalid".to_string()))
+        );
+        assert_eq!(partial.check(&"abc123".into()), Ok(Notice::Clear));
+        assert_eq!(full.check(&"abc".into()), Ok(Notice::Clear));
+        assert_eq!(full.describe(), "fully matching /[a-z]+/");
+
+        let alternation = Checkers::regex_full("a|ab", "invalid").unwrap();
+        assert_eq!(alternation.check(&"ab".into()), Ok(Notice::Clear));
+        assert!(Checkers::regex_full("(", "invalid").is_err());
+
+        let direct = Checkers::RegexFull(
+            regex::Regex::new("[a-z]+").unwrap().try_into().unwrap(),
+            "invalid".to_string(),
+        );
+        assert_eq!(
+            direct.check(&"abc123".into()),
+            Ok(Notice::Attention("invalid".to_string()))
+        );
+        assert_eq!(direct.check(&"abc".into()), Ok(Notice::Clear));
+
+        let anchored = Checkers::regex_full("^(?:a)$", "invalid").unwrap();
+        assert_eq!(anchored.describe(), "fully matching /^(?:a)$/");
+    }
+
+    #[cfg(feature = "cron")]
+    #[test]
+    fn test_cron() {
+        let checker = Checkers::Cron("invalid cron".to_string());
+        assert_eq!(checker.check(&"0 0 * * *".into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&"0 0 * * 0".into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&"0 0 * * 7".into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&"0 9 * * 1-5".into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&"0 9 * * 5-7,0".into()), Ok(Notice::Clear));
+        assert_eq!(checker.check(&"0 9 * * 0-6/2".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"0 0 * * 8".into()),
+            Ok(Notice::Attention("invalid cron".to_string()))
+        );
+        assert_eq!(
+            checker.check(&"0 30 9 * * Mon-Fri".into()),
+            Ok(Notice::Clear)
+        );
+        assert_eq!(
+            checker.check(&"not a cron".into()),
+            Ok(Notice::Attention("invalid cron".to_string()))
+        );
+        assert_eq!(
+            checker.check(&"61 * * * *".into()),
+            Ok(Notice::Attention("invalid cron".to_string()))
+        );
+    }
+
+    #[cfg(feature = "semver")]
+    #[test]
+    fn test_semver() {
+        let checker = Checkers::SemVer("invalid version".to_string());
+        assert_eq!(checker.check(&"1.2.3".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"1.2".into()),
+            Ok(Notice::Attention("invalid version".to_string()))
+        );
+
+        let checker = Checkers::SemVerReq(
+            semver::VersionReq::parse(">=2.0.0").unwrap(),
+            "too old".to_string(),
+        );
+        assert_eq!(checker.check(&"2.1.0".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"1.9.9".into()),
+            Ok(Notice::Attention("too old".to_string()))
+        );
+        assert_eq!(
+            checker.check(&"latest".into()),
+            Ok(Notice::Attention("too old".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_between_int() {
+        let id = 9007199254740993;
+        let checker = Checkers::BetweenInt(id, id, "unexpected id".to_string());
+        assert_eq!(
+            checker.check(&Value::number("9007199254740993")),
+            Ok(Notice::Clear)
+        );
+        assert_eq!(
+            checker.check(&Value::number("9007199254740992")),
+            Ok(Notice::Attention("unexpected id".to_string()))
+        );
+        assert!(matches!(
+            checker.check(&1.5.into()),
+            Err(CheckError::Parse(_))
+        ));
+
+        let float = Checkers::Between(id as f64, id as f64, "unexpected id".to_string());
+        assert_eq!(
+            float.check(&Value::number("9007199254740992")),
+            Ok(Notice::Clear)
+        );
+    }
+
+    #[test]
+    fn test_trimmed() {
+        let checker = Checkers::Trimmed("untrimmed".to_string());
+        assert_eq!(checker.check(&"ok".into()), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&" ok".into()),
+            Ok(Notice::Attention("untrimmed".to_string()))
+        );
+        assert_eq!(
+            checker.check(&"ok ".into()),
+            Ok(Notice::Attention("untrimmed".to_string()))
+        );
+    }
 }
@@ -0,0 +1,117 @@
+use std::future::Future;
+
+use crate::{
+    check::{CheckError, Checker, Notice},
+    value::{KindSet, Value},
+};
+
+pub trait AsyncChecker {
+    fn check(&self, value: &Value) -> impl Future<Output = Result<Notice, CheckError>> + Send;
+    fn expecting(&self) -> KindSet;
+}
+
+impl<T> AsyncChecker for T
+where
+    T: Checker,
+{
+    fn check(&self, value: &Value) -> impl Future<Output = Result<Notice, CheckError>> + Send {
+        std::future::ready(Checker::check(self, value))
+    }
+
+    fn expecting(&self) -> KindSet {
+        Checker::expecting(self)
+    }
+}
+
+/// `f` takes the value by value so the future it returns can be `Send`.
+pub struct AsyncFnChecker<F> {
+    f: F,
+    expecting: KindSet,
+}
+
+impl<F, Fut> AsyncFnChecker<F>
+where
+    F: Fn(Value) -> Fut,
+    Fut: Future<Output = Result<Notice, CheckError>> + Send,
+{
+    pub fn new(expecting: impl Into<KindSet>, f: F) -> Self {
+        AsyncFnChecker {
+            f,
+            expecting: expecting.into(),
+        }
+    }
+}
+
+impl<F, Fut> AsyncChecker for AsyncFnChecker<F>
+where
+    F: Fn(Value) -> Fut,
+    Fut: Future<Output = Result<Notice, CheckError>> + Send,
+{
+    fn check(&self, value: &Value) -> impl Future<Output = Result<Notice, CheckError>> + Send {
+        (self.f)(value.clone())
+    }
+
+    fn expecting(&self) -> KindSet {
+        self.expecting
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::HashSet, sync::Arc};
+
+    use super::{AsyncChecker, AsyncFnChecker};
+    use crate::{
+        check::{Checkers, Notice},
+        value::KindSet,
+    };
+
+    #[tokio::test]
+    async fn test_async_fn_checker() {
+        let taken = HashSet::from(["alice".to_string()]);
+        let checker = AsyncFnChecker::new(KindSet::LITERAL, |v| {
+            let taken = &taken;
+            async move {
+                tokio::task::yield_now().await;
+                Ok(match taken.contains(&v.to_string()) {
+                    true => Notice::Attention("already taken".to_string()),
+                    false => Notice::Clear,
+                })
+            }
+        });
+        assert_eq!(checker.expecting(), KindSet::LITERAL);
+        assert_eq!(checker.check(&"bob".into()).await, Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&"alice".into()).await,
+            Ok(Notice::Attention("already taken".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_spawn() {
+        let taken = Arc::new(HashSet::from(["alice".to_string()]));
+        let checker = Arc::new(AsyncFnChecker::new(KindSet::LITERAL, move |v| {
+            let taken = taken.clone();
+            async move {
+                Ok(match taken.contains(&v.to_string()) {
+                    true => Notice::Attention("already taken".to_string()),
+                    false => Notice::Clear,
+                })
+            }
+        }));
+        let task = tokio::spawn(async move { checker.check(&"alice".into()).await });
+        assert_eq!(
+            task.await.unwrap(),
+            Ok(Notice::Attention("already taken".to_string()))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_sync_checker() {
+        let checker = Checkers::Between(0.0, 1.0, "caution".to_string());
+        assert_eq!(
+            AsyncChecker::check(&checker, &2.into()).await,
+            Ok(Notice::Attention("caution".to_string()))
+        );
+    }
+}
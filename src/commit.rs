@@ -1,15 +1,242 @@
-use std::collections::HashMap;
+use std::{cmp::Ordering, collections::HashMap};
 
 use itertools::Itertools;
 
+#[cfg(feature = "config")]
+use crate::config::RuleSpec;
 use crate::{
-    check::{CheckError, Checker, Flatten, FlattenError, IntoFlat, Notice},
-    value::{Value, ValueKind},
+    check::{CheckError, Checker, Flatten, FlattenError, IntoFlat, Level, Notice},
+    value::{KindSet, Value},
 };
 
+pub trait MessageResolver {
+    fn resolve(&self, key: &str, value: &Value) -> String;
+}
+
+pub struct DefaultResolver;
+
+impl MessageResolver for DefaultResolver {
+    fn resolve(&self, key: &str, _value: &Value) -> String {
+        key.to_string()
+    }
+}
+
 pub trait CheckList {
     fn commit(&self, key: &str, value: Value) -> Result<Option<Commit>, CheckError>;
-    fn items(&self) -> HashMap<String, Vec<ValueKind>>;
+    fn commit_with_context(
+        &self,
+        key: &str,
+        value: Value,
+        context: &HashMap<String, Value>,
+    ) -> Result<Option<Commit>, CheckError>;
+    fn commit_ref(&self, key: &str, value: &Value) -> Result<Option<Notice>, CheckError>;
+    fn items(&self) -> HashMap<String, KindSet>;
+    fn labels(&self) -> HashMap<String, Vec<String>>;
+    fn weights(&self) -> HashMap<String, f64>;
+    fn possible_messages(&self) -> Vec<(String, String)>;
+    fn rules(&self) -> HashMap<String, Vec<String>>;
+    fn merge(self, other: Self) -> Result<Self, FlattenError>
+    where
+        Self: Sized;
+
+    /// The rules as config specs that `config::from_specs` loads back into an
+    /// equivalent checklist. `None` when some checker has no config counterpart, and
+    /// for checklists that don't override it.
+    #[cfg(feature = "config")]
+    fn snapshot(&self) -> Option<HashMap<String, Vec<RuleSpec>>> {
+        None
+    }
+
+    /// Validates keys the checklist doesn't know with `checker` instead of skipping them.
+    fn with_default<C>(self, checker: C) -> WithDefault<Self, C>
+    where
+        Self: Sized,
+        C: Checker,
+    {
+        WithDefault {
+            list: self,
+            fallback: checker,
+        }
+    }
+
+    fn commit_localized(
+        &self,
+        key: &str,
+        value: Value,
+        resolver: &dyn MessageResolver,
+    ) -> Result<Option<Commit>, CheckError> {
+        Ok(self.commit(key, value)?.map(|commit| {
+            let notice = commit
+                .notice
+                .map_message(|msg| resolver.resolve(&msg, &commit.value));
+            Commit { notice, ..commit }
+        }))
+    }
+
+    fn commit_in(
+        &self,
+        category: &str,
+        key: &str,
+        value: Value,
+    ) -> Result<Option<Commit>, CheckError> {
+        Ok(self.commit(key, value)?.map(|commit| Commit {
+            category: Some(category.to_string()),
+            ..commit
+        }))
+    }
+
+    fn items_sorted(&self) -> Vec<(String, KindSet)> {
+        self.items()
+            .into_iter()
+            .sorted_by(|a, b| a.0.cmp(&b.0))
+            .collect()
+    }
+
+    fn commit_all(&self, values: HashMap<String, Value>) -> Result<Vec<Commit>, CheckError> {
+        let mut commits = Vec::new();
+        for (key, value) in &values {
+            if let Some(commit) = self.commit_with_context(key, value.clone(), &values)? {
+                commits.push(commit);
+            }
+        }
+        commits.sort_by(|a, b| a.key.cmp(&b.key));
+        Ok(commits)
+    }
+
+    fn commit_iter<I>(&self, iter: I) -> impl Iterator<Item = Result<Option<Commit>, CheckError>>
+    where
+        I: Iterator<Item = (String, Value)>,
+    {
+        iter.map(move |(key, value)| self.commit(&key, value))
+    }
+
+    fn failures(&self, values: &HashMap<String, Value>) -> Result<Vec<Commit>, CheckError> {
+        Ok(self
+            .commit_all(values.clone())?
+            .into_iter()
+            .filter(|commit| {
+                matches!(
+                    commit.notice,
+                    Notice::Attention(_)
+                        | Notice::Error(_)
+                        | Notice::AttentionCoded { .. }
+                        | Notice::ErrorCoded { .. }
+                )
+            })
+            .collect())
+    }
+
+    /// `fail_at` is the lowest level that fails the form: `Level::Info` rejects any
+    /// non-clear notice, `Level::Error` tolerates everything below an error.
+    fn is_valid(
+        &self,
+        values: &HashMap<String, Value>,
+        fail_at: Level,
+    ) -> Result<bool, CheckError> {
+        for (key, value) in values {
+            if let Some(commit) = self.commit_with_context(key, value.clone(), values)? {
+                if commit.notice.severity() >= fail_at as u8 {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// Weighted fraction of keys whose value is submitted and clear. An empty checklist
+    /// scores `1.0`.
+    fn score(&self, values: &HashMap<String, Value>) -> Result<f64, CheckError> {
+        let weights = self.weights();
+        let total: f64 = weights.values().sum();
+        if total == 0.0 {
+            return Ok(1.0);
+        }
+        let mut passed = 0.0;
+        for (key, weight) in &weights {
+            if let Some(value) = values.get(key) {
+                if self
+                    .commit_with_context(key, value.clone(), values)?
+                    .is_some_and(|c| c.notice.is_clear())
+                {
+                    passed += weight;
+                }
+            }
+        }
+        Ok(passed / total)
+    }
+
+    fn first_failure(&self, values: &HashMap<String, Value>) -> Result<Option<Commit>, CheckError> {
+        for key in values.keys().sorted() {
+            match self.commit_with_context(key, values[key].clone(), values)? {
+                Some(commit) if !commit.notice.is_clear() => return Ok(Some(commit)),
+                _ => {}
+            }
+        }
+        Ok(None)
+    }
+
+    fn commit_strict(&self, key: &str, value: Value) -> Result<Option<Notice>, String> {
+        match self.commit(key, value).map_err(|e| e.to_string())? {
+            Some(commit) => Result::<Notice, String>::from(commit.notice).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    fn to_schema(&self) -> serde_json::Value {
+        let mut rules = self.rules();
+        let schema = self
+            .items()
+            .into_iter()
+            .map(|(key, kinds)| {
+                let rules = rules.remove(&key).unwrap_or_default();
+                (key, serde_json::json!({ "kinds": kinds, "rules": rules }))
+            })
+            .collect::<serde_json::Map<_, _>>();
+        serde_json::Value::Object(schema)
+    }
+
+    /// Strings become literals, numbers numbers, booleans booleans and `null` a null
+    /// value; nested arrays and objects are passed as their JSON text. Fails with
+    /// `CheckError::InvalidKind` unless `obj` is an object.
+    #[cfg(feature = "serde")]
+    fn commit_json(&self, obj: &serde_json::Value) -> Result<Vec<Commit>, CheckError> {
+        let serde_json::Value::Object(map) = obj else {
+            return Err(CheckError::InvalidKind);
+        };
+        let values = map
+            .iter()
+            .map(|(key, value)| {
+                let value = match value {
+                    serde_json::Value::Null => Value::null(),
+                    serde_json::Value::Bool(b) => (*b).into(),
+                    serde_json::Value::Number(n) => Value::number(n.to_string()),
+                    serde_json::Value::String(s) => s.into(),
+                    other => other.to_string().into(),
+                };
+                (key.to_string(), value)
+            })
+            .collect();
+        self.commit_all(values)
+    }
+
+    fn missing_keys(&self, submitted: &HashMap<String, Value>) -> Vec<String> {
+        self.items()
+            .into_keys()
+            .filter(|key| !submitted.contains_key(key))
+            .sorted()
+            .collect()
+    }
+
+    fn unexpected_keys(&self, submitted: &HashMap<String, Value>) -> Vec<String> {
+        let items = self.items();
+        submitted
+            .keys()
+            .filter(|key| !items.contains_key(*key))
+            .sorted()
+            .cloned()
+            .collect()
+    }
 }
 
 impl<T> CheckList for HashMap<String, Flatten<T>>
@@ -17,22 +244,169 @@ where
     T: Checker,
 {
     fn commit(&self, key: &str, value: Value) -> Result<Option<Commit>, CheckError> {
+        self.commit_with_context(key, value, &HashMap::new())
+    }
+
+    fn commit_with_context(
+        &self,
+        key: &str,
+        value: Value,
+        context: &HashMap<String, Value>,
+    ) -> Result<Option<Commit>, CheckError> {
         let Some(n) = self.get(key) else {
             return Ok(None);
         };
-        let notice = n.check(&value)?;
+        let notice = check_item(n, key, &value, context)?;
         Ok(Some(Commit {
             key: key.to_string(),
             value,
             notice,
+            category: None,
         }))
     }
 
-    fn items(&self) -> HashMap<String, Vec<ValueKind>> {
+    fn commit_ref(&self, key: &str, value: &Value) -> Result<Option<Notice>, CheckError> {
+        self.get(key)
+            .map(|n| check_item(n, key, value, &HashMap::new()))
+            .transpose()
+    }
+
+    fn items(&self) -> HashMap<String, KindSet> {
         self.iter()
             .map(|item| (item.0.to_string(), item.1.expecting()))
             .collect()
     }
+
+    fn labels(&self) -> HashMap<String, Vec<String>> {
+        self.iter()
+            .map(|item| (item.0.to_string(), item.1.labels()))
+            .collect()
+    }
+
+    fn weights(&self) -> HashMap<String, f64> {
+        self.iter()
+            .map(|item| (item.0.to_string(), item.1.weight()))
+            .collect()
+    }
+
+    fn possible_messages(&self) -> Vec<(String, String)> {
+        self.iter()
+            .flat_map(|(key, n)| n.messages().into_iter().map(|msg| (key.to_string(), msg)))
+            .sorted()
+            .dedup()
+            .collect()
+    }
+
+    fn rules(&self) -> HashMap<String, Vec<String>> {
+        self.iter()
+            .map(|item| (item.0.to_string(), item.1.rules()))
+            .collect()
+    }
+
+    #[cfg(feature = "config")]
+    fn snapshot(&self) -> Option<HashMap<String, Vec<RuleSpec>>> {
+        self.iter()
+            .map(|item| Some((item.0.to_string(), item.1.specs()?)))
+            .collect()
+    }
+
+    fn merge(mut self, other: Self) -> Result<Self, FlattenError> {
+        for (k, v) in other {
+            let merged = match self.remove(&k) {
+                Some(current) => current.merge(v)?,
+                None => v,
+            };
+            self.insert(k, merged);
+        }
+        Ok(self)
+    }
+}
+
+pub struct WithDefault<L, C> {
+    list: L,
+    fallback: C,
+}
+
+impl<L, C> CheckList for WithDefault<L, C>
+where
+    L: CheckList,
+    C: Checker,
+{
+    fn commit(&self, key: &str, value: Value) -> Result<Option<Commit>, CheckError> {
+        self.commit_with_context(key, value, &HashMap::new())
+    }
+
+    fn commit_with_context(
+        &self,
+        key: &str,
+        value: Value,
+        context: &HashMap<String, Value>,
+    ) -> Result<Option<Commit>, CheckError> {
+        if let Some(commit) = self.list.commit_with_context(key, value.clone(), context)? {
+            return Ok(Some(commit));
+        }
+        let notice = check_item(&self.fallback, key, &value, context)?;
+        Ok(Some(Commit {
+            key: key.to_string(),
+            value,
+            notice,
+            category: None,
+        }))
+    }
+
+    fn commit_ref(&self, key: &str, value: &Value) -> Result<Option<Notice>, CheckError> {
+        match self.list.commit_ref(key, value)? {
+            Some(notice) => Ok(Some(notice)),
+            None => check_item(&self.fallback, key, value, &HashMap::new()).map(Some),
+        }
+    }
+
+    fn items(&self) -> HashMap<String, KindSet> {
+        self.list.items()
+    }
+
+    fn labels(&self) -> HashMap<String, Vec<String>> {
+        self.list.labels()
+    }
+
+    fn weights(&self) -> HashMap<String, f64> {
+        self.list.weights()
+    }
+
+    fn possible_messages(&self) -> Vec<(String, String)> {
+        self.list.possible_messages()
+    }
+
+    fn rules(&self) -> HashMap<String, Vec<String>> {
+        self.list.rules()
+    }
+
+    fn merge(self, other: Self) -> Result<Self, FlattenError> {
+        Ok(WithDefault {
+            list: self.list.merge(other.list)?,
+            fallback: self.fallback,
+        })
+    }
+}
+
+fn check_item<T>(
+    checker: &T,
+    key: &str,
+    value: &Value,
+    context: &HashMap<String, Value>,
+) -> Result<Notice, CheckError>
+where
+    T: Checker,
+{
+    let expected = checker.expecting();
+    if !expected.contains(value.kind()) {
+        return Err(CheckError::InvalidKindFor {
+            key: key.to_string(),
+            expected: expected.to_vec(),
+            got: value.kind(),
+        });
+    }
+    checker.check_with_context(value, context)
 }
 
 pub trait IntoCheckList {
@@ -45,28 +419,186 @@ where
 {
     fn into_checklist(self) -> Result<impl CheckList, FlattenError> {
         let mut hashmap = HashMap::new();
-        for (k, v) in &self.into_iter().group_by(|x| x.0.to_string()) {
-            hashmap.insert(k, v.map(|x| x.1).into_flat()?);
+        for (k, v) in self.into_iter().into_group_map() {
+            hashmap.insert(k, v.into_iter().into_flat()?);
         }
         Ok(hashmap)
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+pub struct ChecklistBuilder<T> {
+    items: HashMap<String, Vec<T>>,
+}
+
+impl<T> ChecklistBuilder<T>
+where
+    T: Checker,
+{
+    pub fn new() -> Self {
+        ChecklistBuilder {
+            items: HashMap::new(),
+        }
+    }
+
+    pub fn add(&mut self, key: impl Into<String>, checker: T) -> &mut Self {
+        self.items.entry(key.into()).or_default().push(checker);
+        self
+    }
+
+    pub fn build(self) -> Result<impl CheckList, FlattenError> {
+        let mut hashmap = HashMap::new();
+        for (k, v) in self.items {
+            hashmap.insert(k, v.into_iter().into_flat()?);
+        }
+        Ok(hashmap)
+    }
+}
+
+impl<T> Default for ChecklistBuilder<T>
+where
+    T: Checker,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Commit {
     key: String,
     value: Value,
     notice: Notice,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    category: Option<String>,
+}
+
+impl Commit {
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn value(&self) -> &Value {
+        &self.value
+    }
+
+    pub fn notice(&self) -> &Notice {
+        &self.notice
+    }
+
+    pub fn category(&self) -> Option<&str> {
+        self.category.as_deref()
+    }
+}
+
+impl From<Commit> for (String, Value, Notice) {
+    fn from(value: Commit) -> Self {
+        (value.key, value.value, value.notice)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct Summary {
+    pub clear: usize,
+    pub info: usize,
+    pub attention: usize,
+    pub error: usize,
+    pub highest: Notice,
+}
+
+impl Summary {
+    pub fn is_passing(&self) -> bool {
+        self.error == 0
+    }
+}
+
+pub fn summarize(commits: &[Commit]) -> Summary {
+    let mut summary = Summary {
+        clear: 0,
+        info: 0,
+        attention: 0,
+        error: 0,
+        highest: Notice::Clear,
+    };
+    for commit in commits {
+        match &commit.notice {
+            Notice::Clear => summary.clear += 1,
+            Notice::Info(_) => summary.info += 1,
+            Notice::Attention(_) | Notice::AttentionCoded { .. } => summary.attention += 1,
+            Notice::Error(_) | Notice::ErrorCoded { .. } => summary.error += 1,
+        }
+        if commit.notice > summary.highest {
+            summary.highest = commit.notice.clone();
+        }
+    }
+    summary
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SeverityChange {
+    Increased,
+    Decreased,
+    Unchanged,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct CommitDiff {
+    pub severity: SeverityChange,
+    pub value_changed: bool,
+}
+
+pub fn diff(old: &Commit, new: &Commit) -> CommitDiff {
+    let severity = match new.notice.severity().cmp(&old.notice.severity()) {
+        Ordering::Greater => SeverityChange::Increased,
+        Ordering::Less => SeverityChange::Decreased,
+        Ordering::Equal => SeverityChange::Unchanged,
+    };
+    CommitDiff {
+        severity,
+        value_changed: old.value != new.value,
+    }
+}
+
+pub fn worst_exit_code(commits: &[Commit]) -> i32 {
+    commits
+        .iter()
+        .map(|commit| commit.notice.exit_code())
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn summarize_by_category(commits: &[Commit]) -> HashMap<Option<String>, Summary> {
+    commits
+        .iter()
+        .into_group_map_by(|commit| commit.category.clone())
+        .into_iter()
+        .map(|(category, commits)| {
+            let commits = commits.into_iter().cloned().collect_vec();
+            (category, summarize(&commits))
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use crate::{
-        check::{Checkers, Notice, SwitchMode},
+        check::{
+            CheckError, Checker, Checkers, ContextChecker, FlattenError, Labeled, Level, Memoized,
+            Notice, SwitchMode, Weighted,
+        },
         commit::CheckList,
+        value::{KindSet, Value, ValueKind},
     };
 
-    use super::IntoCheckList;
+    use super::{
+        diff, summarize, summarize_by_category, worst_exit_code, ChecklistBuilder, CommitDiff,
+        DefaultResolver, IntoCheckList, MessageResolver, SeverityChange,
+    };
 
     #[test]
     fn test() {
@@ -106,4 +638,947 @@ mod tests {
             Notice::Error("error".to_string())
         );
     }
+
+    #[test]
+    fn test_getters() {
+        let lists = vec![(
+            "A".to_string(),
+            Checkers::Exact("abc".to_string(), "caution".to_string()).into_attention(),
+        )];
+        let map = lists.into_checklist().unwrap();
+        let commit = map.commit("A", "abcd".into()).unwrap().unwrap();
+        assert_eq!(commit.key(), "A");
+        assert_eq!(commit.value(), &"abcd".into());
+        assert_eq!(commit.notice(), &Notice::Attention("caution".to_string()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde() {
+        let lists = vec![(
+            "A".to_string(),
+            Checkers::Between(-2.0, 2.0, "caution".to_string()).into_error(),
+        )];
+        let map = lists.into_checklist().unwrap();
+        let commit = map.commit("A", 3.into()).unwrap().unwrap();
+        let json = serde_json::to_string(&commit).unwrap();
+        assert_eq!(
+            json,
+            r#"{"key":"A","value":{"inner":"3","kind":"number"},"notice":{"type":"error","message":"caution"}}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<super::Commit>(&json).unwrap(),
+            commit
+        );
+    }
+
+    #[test]
+    fn test_commit_all() {
+        let lists = vec![
+            (
+                "A".to_string(),
+                Checkers::Exact("abc".to_string(), "caution".to_string()).into_attention(),
+            ),
+            (
+                "B".to_string(),
+                Checkers::Between(-2.0, 2.0, "error".to_string()).into_error(),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        let values = HashMap::from([
+            ("B".to_string(), 3.into()),
+            ("A".to_string(), "abc".into()),
+            ("Z".to_string(), "unknown".into()),
+        ]);
+        let commits = map.commit_all(values).unwrap();
+        assert_eq!(commits.len(), 2);
+        assert_eq!(commits[0].key(), "A");
+        assert_eq!(commits[0].notice(), &Notice::Clear);
+        assert_eq!(commits[1].key(), "B");
+        assert_eq!(commits[1].notice(), &Notice::Error("error".to_string()));
+    }
+
+    #[test]
+    fn test_invalid_kind_for() {
+        let lists = vec![(
+            "age".to_string(),
+            Checkers::Between(0.0, 150.0, "caution".to_string()).into_attention(),
+        )];
+        let map = lists.into_checklist().unwrap();
+        let err = map.commit("age", "twenty".into()).unwrap_err();
+        assert_eq!(
+            err,
+            CheckError::InvalidKindFor {
+                key: "age".to_string(),
+                expected: vec![ValueKind::Number],
+                got: ValueKind::Literal,
+            }
+        );
+        assert!(err.to_string().contains("age"));
+    }
+
+    #[test]
+    fn test_commit_null_grouped() {
+        let lists = vec![
+            (
+                "name".to_string(),
+                Checkers::Required("required".to_string()),
+            ),
+            ("name".to_string(), Checkers::NotEmpty("empty".to_string())),
+            (
+                "nick".to_string(),
+                Checkers::Optional(Box::new(Checkers::NotEmpty("empty".to_string()))),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        assert_eq!(
+            map.commit("name", Value::null()).unwrap().unwrap().notice,
+            Notice::Attention("required".to_string())
+        );
+        assert_eq!(
+            map.commit("name", " ".into()).unwrap().unwrap().notice,
+            Notice::Attention("empty".to_string())
+        );
+        assert_eq!(
+            map.commit("nick", Value::null()).unwrap().unwrap().notice,
+            Notice::Clear
+        );
+    }
+
+    #[test]
+    fn test_builder() {
+        let mut builder = ChecklistBuilder::new();
+        builder.add(
+            "B",
+            Checkers::Between(-2.0, 2.0, "caution".to_string()).into_attention(),
+        );
+        builder.add("A", Checkers::Any.into_attention());
+        builder.add(
+            "B",
+            Checkers::Between(-5.0, 5.0, "error".to_string()).into_error(),
+        );
+        let map = builder.build().unwrap();
+        assert_eq!(
+            map.commit("B", 3.into()).unwrap().unwrap().notice,
+            Notice::Attention("caution".to_string())
+        );
+        assert_eq!(
+            map.commit("B", 6.into()).unwrap().unwrap().notice,
+            Notice::Error("error".to_string())
+        );
+    }
+
+    #[test]
+    fn test_boxed_checkers() {
+        let lists: Vec<(String, Box<dyn Checker>)> = vec![
+            (
+                "A".to_string(),
+                Box::new(Checkers::Between(-2.0, 2.0, "caution".to_string())),
+            ),
+            (
+                "B".to_string(),
+                Box::new(Checkers::Exact("abc".to_string(), "error".to_string()).into_error()),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        assert_eq!(
+            map.commit("A", 3.into()).unwrap().unwrap().notice,
+            Notice::Attention("caution".to_string())
+        );
+        assert_eq!(
+            map.commit("B", "abd".into()).unwrap().unwrap().notice,
+            Notice::Error("error".to_string())
+        );
+    }
+
+    #[test]
+    fn test_summarize() {
+        let lists = vec![
+            (
+                "A".to_string(),
+                Checkers::Exact("abc".to_string(), "caution".to_string()).into_attention(),
+            ),
+            (
+                "B".to_string(),
+                Checkers::Between(-2.0, 2.0, "error".to_string()).into_error(),
+            ),
+            ("C".to_string(), Checkers::Any.into_attention()),
+        ];
+        let map = lists.into_checklist().unwrap();
+        let values = HashMap::from([
+            ("A".to_string(), "abd".into()),
+            ("B".to_string(), 3.into()),
+            ("C".to_string(), "anything".into()),
+        ]);
+        let summary = summarize(&map.commit_all(values).unwrap());
+        assert_eq!(summary.clear, 1);
+        assert_eq!(summary.attention, 1);
+        assert_eq!(summary.error, 1);
+        assert_eq!(summary.highest, Notice::Error("error".to_string()));
+        assert!(!summary.is_passing());
+
+        let summary = summarize(&[]);
+        assert_eq!(summary.highest, Notice::Clear);
+        assert!(summary.is_passing());
+    }
+
+    #[test]
+    fn test_missing_keys() {
+        let lists = vec![
+            ("C".to_string(), Checkers::Any),
+            ("A".to_string(), Checkers::Any),
+            ("B".to_string(), Checkers::Any),
+        ];
+        let map = lists.into_checklist().unwrap();
+        let submitted = HashMap::from([("A".to_string(), "a".into())]);
+        assert_eq!(
+            map.missing_keys(&submitted),
+            vec!["B".to_string(), "C".to_string()]
+        );
+    }
+
+    #[test]
+    fn test_commit_strict() {
+        let lists = vec![
+            (
+                "A".to_string(),
+                Checkers::Between(-2.0, 2.0, "caution".to_string()).into_attention(),
+            ),
+            (
+                "B".to_string(),
+                Checkers::Between(-2.0, 2.0, "error".to_string()).into_error(),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        assert_eq!(map.commit_strict("A", 0.into()), Ok(Some(Notice::Clear)));
+        assert_eq!(
+            map.commit_strict("A", 3.into()),
+            Ok(Some(Notice::Attention("caution".to_string())))
+        );
+        assert_eq!(map.commit_strict("B", 3.into()), Err("error".to_string()));
+        assert_eq!(map.commit_strict("Z", 3.into()), Ok(None));
+    }
+
+    #[test]
+    fn test_labels() {
+        let lists = vec![
+            (
+                "zip".to_string(),
+                Labeled(
+                    Checkers::Regex(
+                        regex::Regex::new("^[0-9]{3}-[0-9]{4}$").unwrap(),
+                        "caution".to_string(),
+                    ),
+                    "address.zip".to_string(),
+                ),
+            ),
+            (
+                "name".to_string(),
+                Labeled(Checkers::Any, "profile.name".to_string()),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        let labels = map.labels();
+        assert_eq!(labels["zip"], vec!["address.zip".to_string()]);
+        assert_eq!(labels["name"], vec!["profile.name".to_string()]);
+        assert_eq!(
+            map.commit("zip", "100-0001".into())
+                .unwrap()
+                .unwrap()
+                .notice,
+            Notice::Clear
+        );
+    }
+
+    #[test]
+    fn test_into_tuple() {
+        let lists = vec![(
+            "A".to_string(),
+            Checkers::Exact("abc".to_string(), "caution".to_string()).into_attention(),
+        )];
+        let map = lists.into_checklist().unwrap();
+        let (key, value, notice) = map.commit("A", "abd".into()).unwrap().unwrap().into();
+        assert_eq!(key, "A");
+        assert_eq!(value, "abd".into());
+        assert_eq!(notice, Notice::Attention("caution".to_string()));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_to_schema() {
+        let lists = vec![
+            (
+                "A".to_string(),
+                Checkers::Exact("abc".to_string(), "caution".to_string()).into_attention(),
+            ),
+            (
+                "B".to_string(),
+                Checkers::Between(-2.0, 2.0, "caution".to_string()).into_attention(),
+            ),
+            (
+                "B".to_string(),
+                Checkers::Between(-5.0, 5.0, "error".to_string()).into_error(),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        assert_eq!(
+            map.to_schema(),
+            serde_json::json!({
+                "A": {
+                    "kinds": ["number", "literal"],
+                    "rules": ["exactly \"abc\""],
+                },
+                "B": {
+                    "kinds": ["number"],
+                    "rules": ["between -2 and 2", "between -5 and 5"],
+                },
+            })
+        );
+    }
+
+    #[test]
+    fn test_merge() {
+        let build = |lists: Vec<(String, Checkers)>| lists.into_checklist().unwrap();
+        let base = build(vec![
+            (
+                "A".to_string(),
+                Checkers::Between(-5.0, 5.0, "base".to_string()),
+            ),
+            ("B".to_string(), Checkers::NotEmpty("required".to_string())),
+        ]);
+        let context = build(vec![
+            (
+                "A".to_string(),
+                Checkers::Between(-2.0, 2.0, "context".to_string()),
+            ),
+            ("C".to_string(), Checkers::Any),
+        ]);
+        let merged = base.merge(context).unwrap();
+        assert_eq!(merged.items().len(), 3);
+        assert_eq!(
+            merged.commit("A", 3.into()).unwrap().unwrap().notice,
+            Notice::Attention("context".to_string())
+        );
+        assert_eq!(
+            merged.commit("A", 6.into()).unwrap().unwrap().notice,
+            Notice::Attention("context".to_string())
+        );
+
+        let conflicting = build(vec![(
+            "B".to_string(),
+            Checkers::Between(0.0, 1.0, "number".to_string()),
+        )]);
+        assert_eq!(
+            merged.merge(conflicting).err(),
+            Some(FlattenError::InvalidKind)
+        );
+    }
+
+    #[test]
+    fn test_commit_with_context() {
+        struct GreaterThanField(String);
+
+        impl ContextChecker for GreaterThanField {
+            fn check(
+                &self,
+                value: &Value,
+                context: &HashMap<String, Value>,
+            ) -> Result<Notice, CheckError> {
+                let Some(other) = context.get(&self.0) else {
+                    return Ok(Notice::Clear);
+                };
+                let v = f64::try_from(value).map_err(CheckError::Parse)?;
+                let other = f64::try_from(other).map_err(CheckError::Parse)?;
+                Ok(match v > other {
+                    true => Notice::Clear,
+                    false => Notice::Attention(format!("must exceed {}", self.0)),
+                })
+            }
+
+            fn expecting(&self) -> KindSet {
+                KindSet::NUMBER
+            }
+        }
+
+        let lists = vec![
+            (
+                "end".to_string(),
+                Checkers::CustomContext(Box::new(GreaterThanField("start".to_string())))
+                    .into_error(),
+            ),
+            (
+                "start".to_string(),
+                Checkers::GreaterOrEqual(0.0, "negative".to_string()).into_error(),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        let context = HashMap::from([
+            ("start".to_string(), 10.into()),
+            ("end".to_string(), 5.into()),
+        ]);
+        assert_eq!(
+            map.commit_with_context("end", 5.into(), &context)
+                .unwrap()
+                .unwrap()
+                .notice,
+            Notice::Error("must exceed start".to_string())
+        );
+        assert_eq!(
+            map.commit_with_context("end", 20.into(), &context)
+                .unwrap()
+                .unwrap()
+                .notice,
+            Notice::Clear
+        );
+        assert_eq!(
+            map.commit_with_context("start", 10.into(), &context)
+                .unwrap()
+                .unwrap()
+                .notice,
+            Notice::Clear
+        );
+    }
+
+    #[test]
+    fn test_items_sorted() {
+        let lists = vec![
+            ("C".to_string(), Checkers::NotEmpty("required".to_string())),
+            (
+                "A".to_string(),
+                Checkers::Between(0.0, 1.0, "caution".to_string()),
+            ),
+            ("B".to_string(), Checkers::NotEmpty("required".to_string())),
+        ];
+        let map = lists.into_checklist().unwrap();
+        assert_eq!(
+            map.items_sorted(),
+            vec![
+                ("A".to_string(), KindSet::NUMBER),
+                ("B".to_string(), KindSet::LITERAL),
+                ("C".to_string(), KindSet::LITERAL),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_equals_field() {
+        let lists = vec![(
+            "confirm".to_string(),
+            Checkers::EqualsField("password".to_string(), "mismatch".to_string()),
+        )];
+        let map = lists.into_checklist().unwrap();
+        let context = HashMap::from([("password".to_string(), "hunter2".into())]);
+        assert_eq!(
+            map.commit_with_context("confirm", "hunter2".into(), &context)
+                .unwrap()
+                .unwrap()
+                .notice,
+            Notice::Clear
+        );
+        assert_eq!(
+            map.commit_with_context("confirm", "hunter3".into(), &context)
+                .unwrap()
+                .unwrap()
+                .notice,
+            Notice::Attention("mismatch".to_string())
+        );
+        assert_eq!(
+            map.commit("confirm", "hunter2".into()),
+            Err(CheckError::MissingField("password".to_string()))
+        );
+
+        let values = HashMap::from([
+            ("password".to_string(), "hunter2".into()),
+            ("confirm".to_string(), "hunter3".into()),
+        ]);
+        let commits = map.commit_all(values.clone()).unwrap();
+        assert_eq!(commits.len(), 1);
+        assert_eq!(
+            commits[0].notice(),
+            &Notice::Attention("mismatch".to_string())
+        );
+        assert_eq!(map.failures(&values).unwrap().len(), 1);
+        assert_eq!(
+            map.first_failure(&values).unwrap().unwrap().key(),
+            "confirm"
+        );
+        assert_eq!(map.score(&values), Ok(0.0));
+    }
+
+    #[test]
+    fn test_memoized_commit_all() {
+        use std::{cell::Cell, rc::Rc};
+
+        let calls = Rc::new(Cell::new(0));
+        let counter = calls.clone();
+        let lists = vec![(
+            "name".to_string(),
+            Memoized::new(Checkers::from_fn(KindSet::LITERAL, move |_| {
+                counter.set(counter.get() + 1);
+                Ok(Notice::Clear)
+            })),
+        )];
+        let map = lists.into_checklist().unwrap();
+        let values = HashMap::from([
+            ("name".to_string(), "taro".into()),
+            ("age".to_string(), 20.into()),
+        ]);
+        map.commit_all(values.clone()).unwrap();
+        map.commit_all(values).unwrap();
+        assert_eq!(calls.get(), 1);
+    }
+
+    #[test]
+    fn test_commit_localized() {
+        struct German;
+
+        impl MessageResolver for German {
+            fn resolve(&self, key: &str, value: &Value) -> String {
+                match key {
+                    "caution" => "Achtung".to_string(),
+                    "too large" => format!("{} ist zu groß", value),
+                    _ => key.to_string(),
+                }
+            }
+        }
+
+        let lists = vec![
+            (
+                "A".to_string(),
+                Checkers::Exact("abc".to_string(), "caution".to_string()),
+            ),
+            (
+                "B".to_string(),
+                Checkers::Exact("abc".to_string(), "too large".to_string()),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        assert_eq!(
+            map.commit_localized("A", "abd".into(), &German)
+                .unwrap()
+                .unwrap()
+                .notice,
+            Notice::Attention("Achtung".to_string())
+        );
+        assert_eq!(
+            map.commit_localized("B", "abd".into(), &German)
+                .unwrap()
+                .unwrap()
+                .notice,
+            Notice::Attention("abd ist zu groß".to_string())
+        );
+        assert_eq!(
+            map.commit_localized("A", "abd".into(), &DefaultResolver)
+                .unwrap()
+                .unwrap()
+                .notice,
+            Notice::Attention("caution".to_string())
+        );
+    }
+
+    #[test]
+    fn test_failures() {
+        let lists = vec![
+            (
+                "A".to_string(),
+                Checkers::Exact("abc".to_string(), "caution".to_string()).into_attention(),
+            ),
+            (
+                "B".to_string(),
+                Checkers::Between(-2.0, 2.0, "error".to_string()).into_error(),
+            ),
+            (
+                "C".to_string(),
+                Checkers::NotEmpty("required".to_string()).into_attention(),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        let values = HashMap::from([
+            ("A".to_string(), "abd".into()),
+            ("B".to_string(), 3.into()),
+            ("C".to_string(), "filled".into()),
+        ]);
+        let failures = map.failures(&values).unwrap();
+        assert_eq!(
+            failures.iter().map(|c| c.key()).collect::<Vec<_>>(),
+            vec!["A", "B"]
+        );
+    }
+
+    #[test]
+    fn test_commit_in() {
+        let lists = vec![
+            (
+                "amount".to_string(),
+                Checkers::Between(0.0, 100.0, "out of range".to_string()).into_error(),
+            ),
+            (
+                "currency".to_string(),
+                Checkers::Exact("JPY".to_string(), "unsupported".to_string()).into_attention(),
+            ),
+            (
+                "name".to_string(),
+                Checkers::NotEmpty("empty".to_string()).into_attention(),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        let commits = vec![
+            map.commit_in("billing", "amount", 120.into())
+                .unwrap()
+                .unwrap(),
+            map.commit_in("billing", "currency", "JPY".into())
+                .unwrap()
+                .unwrap(),
+            map.commit("name", "taro".into()).unwrap().unwrap(),
+        ];
+        assert_eq!(commits[0].category(), Some("billing"));
+        assert_eq!(commits[1].category(), Some("billing"));
+        assert_eq!(commits[2].category(), None);
+
+        let summaries = summarize_by_category(&commits);
+        let billing = &summaries[&Some("billing".to_string())];
+        assert_eq!(billing.clear, 1);
+        assert_eq!(billing.error, 1);
+        assert_eq!(summaries[&None].clear, 1);
+    }
+
+    #[test]
+    fn test_commit_iter() {
+        let lists = vec![
+            (
+                "A".to_string(),
+                Checkers::Exact("abc".to_string(), "caution".to_string()).into_attention(),
+            ),
+            (
+                "B".to_string(),
+                Checkers::Between(-2.0, 2.0, "error".to_string()).into_error(),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        let rows = vec![
+            ("A".to_string(), "abc".into()),
+            ("B".to_string(), 1.into()),
+            ("B".to_string(), 3.into()),
+            ("Z".to_string(), "unknown".into()),
+            ("B".to_string(), "three".into()),
+        ];
+        let results = map.commit_iter(rows.into_iter()).collect::<Vec<_>>();
+        assert_eq!(results.len(), 5);
+        assert_eq!(
+            results[0].as_ref().unwrap().as_ref().unwrap().notice(),
+            &Notice::Clear
+        );
+        assert_eq!(
+            results[1].as_ref().unwrap().as_ref().unwrap().notice(),
+            &Notice::Clear
+        );
+        assert_eq!(
+            results[2].as_ref().unwrap().as_ref().unwrap().notice(),
+            &Notice::Error("error".to_string())
+        );
+        assert_eq!(results[3], Ok(None));
+        assert!(results[4].is_err());
+    }
+
+    #[test]
+    fn test_commit_null() {
+        let lists = vec![(
+            "name".to_string(),
+            Checkers::Required("name is required".to_string()).into_error(),
+        )];
+        let map = lists.into_checklist().unwrap();
+        assert_eq!(
+            map.commit("name", Value::null()).unwrap().unwrap().notice(),
+            &Notice::Error("name is required".to_string())
+        );
+        assert_eq!(
+            map.commit("name", "taro".into()).unwrap().unwrap().notice(),
+            &Notice::Clear
+        );
+    }
+
+    #[test]
+    fn test_first_failure() {
+        let lists = vec![
+            (
+                "A".to_string(),
+                Checkers::Exact("abc".to_string(), "caution".to_string()).into_attention(),
+            ),
+            (
+                "B".to_string(),
+                Checkers::Between(-2.0, 2.0, "error B".to_string()).into_error(),
+            ),
+            (
+                "C".to_string(),
+                Checkers::Between(-2.0, 2.0, "error C".to_string()).into_error(),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        let mut values = HashMap::from([
+            ("C".to_string(), 3.into()),
+            ("A".to_string(), "abc".into()),
+            ("B".to_string(), 3.into()),
+        ]);
+        let commit = map.first_failure(&values).unwrap().unwrap();
+        assert_eq!(commit.key(), "B");
+        assert_eq!(commit.notice(), &Notice::Error("error B".to_string()));
+
+        values.insert("B".to_string(), 0.into());
+        values.insert("C".to_string(), 0.into());
+        assert_eq!(map.first_failure(&values), Ok(None));
+    }
+
+    #[test]
+    fn test_diff() {
+        let lists = vec![(
+            "A".to_string(),
+            Checkers::Exact("abc".to_string(), "caution".to_string()).into_attention(),
+        )];
+        let map = lists.into_checklist().unwrap();
+        let old = map.commit("A", "abd".into()).unwrap().unwrap();
+        let new = map.commit("A", "abc".into()).unwrap().unwrap();
+        assert_eq!(
+            diff(&old, &new),
+            CommitDiff {
+                severity: SeverityChange::Decreased,
+                value_changed: true,
+            }
+        );
+        assert_eq!(diff(&new, &old).severity, SeverityChange::Increased);
+        assert_eq!(
+            diff(&new, &new),
+            CommitDiff {
+                severity: SeverityChange::Unchanged,
+                value_changed: false,
+            }
+        );
+    }
+
+    #[test]
+    fn test_commit_ref() {
+        let lists = vec![(
+            "B".to_string(),
+            Checkers::Between(-2.0, 2.0, "error".to_string()).into_error(),
+        )];
+        let map = lists.into_checklist().unwrap();
+        let value: Value = 3.into();
+        assert_eq!(
+            map.commit_ref("B", &value),
+            Ok(Some(Notice::Error("error".to_string())))
+        );
+        assert_eq!(map.commit_ref("Z", &value), Ok(None));
+        assert!(map.commit_ref("B", &"three".into()).is_err());
+        assert_eq!(value, 3.into());
+    }
+
+    #[test]
+    fn test_unexpected_keys() {
+        let lists = vec![(
+            "name".to_string(),
+            Checkers::NotEmpty("empty".to_string()).into_attention(),
+        )];
+        let map = lists.into_checklist().unwrap();
+        let submitted = HashMap::from([
+            ("xyz".to_string(), "typo".into()),
+            ("name".to_string(), "taro".into()),
+            ("abc".to_string(), "extra".into()),
+        ]);
+        assert_eq!(map.unexpected_keys(&submitted), vec!["abc", "xyz"]);
+    }
+
+    #[test]
+    fn test_is_valid() {
+        let lists = vec![
+            (
+                "A".to_string(),
+                Checkers::Exact("abc".to_string(), "caution".to_string()).into_attention(),
+            ),
+            (
+                "B".to_string(),
+                Checkers::Between(-2.0, 2.0, "error".to_string()).into_error(),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        let mut values =
+            HashMap::from([("A".to_string(), "abd".into()), ("B".to_string(), 0.into())]);
+        assert_eq!(map.is_valid(&values, Level::Error), Ok(true));
+        assert_eq!(map.is_valid(&values, Level::Attention), Ok(false));
+        assert_eq!(map.is_valid(&values, Level::Info), Ok(false));
+
+        values.insert("B".to_string(), 3.into());
+        assert_eq!(map.is_valid(&values, Level::Error), Ok(false));
+    }
+
+    #[test]
+    fn test_with_default() {
+        let lists = vec![(
+            "A".to_string(),
+            Checkers::Exact("abc".to_string(), "caution".to_string()).into_attention(),
+        )];
+        let map = lists
+            .into_checklist()
+            .unwrap()
+            .with_default(Checkers::NotEmpty("unknown key is empty".to_string()).into_error());
+        assert_eq!(
+            map.commit("A", "abd".into()).unwrap().unwrap().notice(),
+            &Notice::Attention("caution".to_string())
+        );
+        assert_eq!(
+            map.commit("Z", "".into()).unwrap().unwrap().notice(),
+            &Notice::Error("unknown key is empty".to_string())
+        );
+        assert_eq!(map.commit_ref("Z", &"zzz".into()), Ok(Some(Notice::Clear)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_commit_json() {
+        let lists: Vec<(String, Box<dyn Checker>)> = vec![
+            (
+                "name".to_string(),
+                Box::new(Checkers::NotEmpty("empty".to_string()).into_attention()),
+            ),
+            (
+                "age".to_string(),
+                Box::new(Checkers::Between(0.0, 150.0, "out of range".to_string()).into_error()),
+            ),
+            (
+                "agreed".to_string(),
+                Box::new(Checkers::IsTrue("must agree".to_string()).into_error()),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        let obj = serde_json::json!({"name": "taro", "age": 200, "agreed": true, "extra": 1});
+        let commits = map.commit_json(&obj).unwrap();
+        assert_eq!(commits.len(), 3);
+        assert_eq!(commits[0].key(), "age");
+        assert_eq!(
+            commits[0].notice(),
+            &Notice::Error("out of range".to_string())
+        );
+        assert_eq!(commits[1].key(), "agreed");
+        assert_eq!(commits[1].notice(), &Notice::Clear);
+        assert_eq!(commits[2].key(), "name");
+        assert_eq!(commits[2].notice(), &Notice::Clear);
+
+        assert_eq!(
+            map.commit_json(&serde_json::json!([1, 2])),
+            Err(CheckError::InvalidKind)
+        );
+    }
+
+    #[test]
+    fn test_into_checklist_interleaved() {
+        let lists = vec![
+            (
+                "A".to_string(),
+                Checkers::Between(-5.0, 5.0, "error".to_string()).into_error(),
+            ),
+            (
+                "B".to_string(),
+                Checkers::Between(-2.0, 2.0, "caution".to_string()).into_attention(),
+            ),
+            (
+                "A".to_string(),
+                Checkers::Between(-2.0, 2.0, "caution".to_string()).into_attention(),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        assert_eq!(map.rules()["A"].len(), 2);
+        assert_eq!(
+            map.commit("A", 3.into()).unwrap().unwrap().notice(),
+            &Notice::Attention("caution".to_string())
+        );
+        assert_eq!(
+            map.commit("A", 6.into()).unwrap().unwrap().notice(),
+            &Notice::Error("error".to_string())
+        );
+    }
+
+    #[test]
+    fn test_score() {
+        let lists = vec![
+            (
+                "name".to_string(),
+                Weighted(
+                    Checkers::NotEmpty("empty".to_string()).into_attention(),
+                    2.0,
+                ),
+            ),
+            (
+                "bio".to_string(),
+                Weighted(
+                    Checkers::NotEmpty("empty".to_string()).into_attention(),
+                    1.0,
+                ),
+            ),
+            (
+                "avatar".to_string(),
+                Weighted(
+                    Checkers::NotEmpty("empty".to_string()).into_attention(),
+                    1.0,
+                ),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        let values = HashMap::from([
+            ("name".to_string(), "taro".into()),
+            ("bio".to_string(), "hello".into()),
+            ("avatar".to_string(), "".into()),
+        ]);
+        assert_eq!(map.score(&values), Ok(0.75));
+        assert_eq!(map.score(&HashMap::new()), Ok(0.0));
+    }
+
+    #[test]
+    fn test_worst_exit_code() {
+        let lists = vec![
+            (
+                "A".to_string(),
+                Checkers::Exact("abc".to_string(), "caution".to_string()).into_attention(),
+            ),
+            (
+                "B".to_string(),
+                Checkers::Between(-2.0, 2.0, "error".to_string()).into_error(),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        let clear = map.commit("A", "abc".into()).unwrap().unwrap();
+        let attention = map.commit("A", "abd".into()).unwrap().unwrap();
+        let error = map.commit("B", 3.into()).unwrap().unwrap();
+        assert_eq!(clear.notice().exit_code(), 0);
+        assert_eq!(attention.notice().exit_code(), 1);
+        assert_eq!(error.notice().exit_code(), 2);
+        assert_eq!(Notice::Info("note".to_string()).exit_code(), 0);
+
+        assert_eq!(worst_exit_code(&[]), 0);
+        assert_eq!(worst_exit_code(&[clear.clone(), attention.clone()]), 1);
+        assert_eq!(worst_exit_code(&[clear, error, attention]), 2);
+    }
+
+    #[test]
+    fn test_possible_messages() {
+        let lists: Vec<(String, Box<dyn Checker>)> = vec![
+            (
+                "B".to_string(),
+                Box::new(Checkers::Between(-2.0, 2.0, "caution".to_string()).into_attention()),
+            ),
+            (
+                "B".to_string(),
+                Box::new(Checkers::Between(-5.0, 5.0, "error".to_string()).into_error()),
+            ),
+            (
+                "C".to_string(),
+                Box::new(Checkers::from_fn(KindSet::NUMBER, |_| Ok(Notice::Clear))),
+            ),
+        ];
+        let map = lists.into_checklist().unwrap();
+        assert_eq!(
+            map.possible_messages(),
+            vec![
+                ("B".to_string(), "caution".to_string()),
+                ("B".to_string(), "error".to_string()),
+            ]
+        );
+    }
 }
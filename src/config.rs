@@ -0,0 +1,491 @@
+use std::{collections::HashMap, error::Error, fmt::Display};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    check::{CheckerMode, Checkers, FlattenError, SwitchMode},
+    commit::{CheckList, IntoCheckList},
+};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Mode {
+    Attention,
+    Error,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct RuleSpec {
+    /// Optional here so a rule without a `type` reports `ConfigError::MissingField`.
+    #[serde(rename = "type", default, skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max: Option<f64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub value: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub values: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pattern: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<Mode>,
+}
+
+#[derive(Debug)]
+enum Rules {
+    One(RuleSpec),
+    Many(Vec<RuleSpec>),
+}
+
+/// Hand-written rather than `untagged` so errors from inside a rule, like an unknown
+/// field, reach the caller instead of a generic "did not match any variant".
+impl<'de> Deserialize<'de> for Rules {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        struct RulesVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for RulesVisitor {
+            type Value = Rules;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a rule or a list of rules")
+            }
+
+            fn visit_map<A>(self, map: A) -> Result<Rules, A::Error>
+            where
+                A: serde::de::MapAccess<'de>,
+            {
+                RuleSpec::deserialize(serde::de::value::MapAccessDeserializer::new(map))
+                    .map(Rules::One)
+            }
+
+            fn visit_seq<A>(self, seq: A) -> Result<Rules, A::Error>
+            where
+                A: serde::de::SeqAccess<'de>,
+            {
+                Vec::deserialize(serde::de::value::SeqAccessDeserializer::new(seq)).map(Rules::Many)
+            }
+        }
+
+        deserializer.deserialize_any(RulesVisitor)
+    }
+}
+
+impl From<Rules> for Vec<RuleSpec> {
+    fn from(value: Rules) -> Self {
+        match value {
+            Rules::One(rule) => vec![rule],
+            Rules::Many(rules) => rules,
+        }
+    }
+}
+
+impl RuleSpec {
+    /// The inverse of `into_checker`, without the mode. `None` for variants the
+    /// loader doesn't know.
+    pub(crate) fn from_checker(checker: &Checkers) -> Option<RuleSpec> {
+        let rule = |kind: &str, msg: &str| RuleSpec {
+            kind: Some(kind.to_string()),
+            message: Some(msg.to_string()),
+            ..Default::default()
+        };
+        Some(match checker {
+            Checkers::Any => RuleSpec {
+                kind: Some("any".to_string()),
+                ..Default::default()
+            },
+            Checkers::Exact(v, msg) => RuleSpec {
+                value: Some(v.clone()),
+                ..rule("exact", msg)
+            },
+            Checkers::ExactIgnoreCase(v, msg) => RuleSpec {
+                value: Some(v.clone()),
+                ..rule("exact_ignore_case", msg)
+            },
+            Checkers::Regex(re, msg) => RuleSpec {
+                pattern: Some(re.as_str().to_string()),
+                ..rule("regex", msg)
+            },
+            Checkers::Between(min, max, msg) => RuleSpec {
+                min: Some(*min),
+                max: Some(*max),
+                ..rule("between", msg)
+            },
+            Checkers::GreaterThan(min, msg) => RuleSpec {
+                min: Some(*min),
+                ..rule("greater_than", msg)
+            },
+            Checkers::GreaterOrEqual(min, msg) => RuleSpec {
+                min: Some(*min),
+                ..rule("greater_or_equal", msg)
+            },
+            Checkers::LessThan(max, msg) => RuleSpec {
+                max: Some(*max),
+                ..rule("less_than", msg)
+            },
+            Checkers::LessOrEqual(max, msg) => RuleSpec {
+                max: Some(*max),
+                ..rule("less_or_equal", msg)
+            },
+            Checkers::OneOf(values, msg) => RuleSpec {
+                values: Some(values.clone()),
+                ..rule("one_of", msg)
+            },
+            Checkers::NotEmpty(msg) => rule("not_empty", msg),
+            Checkers::Trimmed(msg) => rule("trimmed", msg),
+            Checkers::Contains(v, msg) => RuleSpec {
+                value: Some(v.clone()),
+                ..rule("contains", msg)
+            },
+            Checkers::StartsWith(v, msg) => RuleSpec {
+                value: Some(v.clone()),
+                ..rule("starts_with", msg)
+            },
+            Checkers::EndsWith(v, msg) => RuleSpec {
+                value: Some(v.clone()),
+                ..rule("ends_with", msg)
+            },
+            Checkers::IsInteger(msg) => rule("is_integer", msg),
+            Checkers::IsNumeric(msg) => rule("is_numeric", msg),
+            _ => return None,
+        })
+    }
+
+    fn into_checker(self, key: &str) -> Result<CheckerMode<Checkers>, ConfigError> {
+        let invalid = |field: &str| ConfigError::MissingField {
+            rule: key.to_string(),
+            field: field.to_string(),
+        };
+        let message = || self.message.clone().ok_or_else(|| invalid("message"));
+        let min = || self.min.ok_or_else(|| invalid("min"));
+        let max = || self.max.ok_or_else(|| invalid("max"));
+        let value = || self.value.clone().ok_or_else(|| invalid("value"));
+        let kind = self.kind.clone().ok_or_else(|| invalid("type"))?;
+        let checker = match kind.as_str() {
+            "any" => Checkers::Any,
+            "exact" => Checkers::Exact(value()?, message()?),
+            "exact_ignore_case" => Checkers::ExactIgnoreCase(value()?, message()?),
+            "regex" => {
+                let pattern = self.pattern.clone().ok_or_else(|| invalid("pattern"))?;
+                Checkers::regex_str(&pattern, &message()?)
+                    .map_err(|e| ConfigError::Invalid(e.to_string()))?
+            }
+            "between" => Checkers::Between(min()?, max()?, message()?),
+            "greater_than" => Checkers::GreaterThan(min()?, message()?),
+            "greater_or_equal" => Checkers::GreaterOrEqual(min()?, message()?),
+            "less_than" => Checkers::LessThan(max()?, message()?),
+            "less_or_equal" => Checkers::LessOrEqual(max()?, message()?),
+            "one_of" => Checkers::OneOf(
+                self.values.clone().ok_or_else(|| invalid("values"))?,
+                message()?,
+            ),
+            "not_empty" => Checkers::NotEmpty(message()?),
+            "trimmed" => Checkers::Trimmed(message()?),
+            "contains" => Checkers::Contains(value()?, message()?),
+            "starts_with" => Checkers::StartsWith(value()?, message()?),
+            "ends_with" => Checkers::EndsWith(value()?, message()?),
+            "is_integer" => Checkers::IsInteger(message()?),
+            "is_numeric" => Checkers::IsNumeric(message()?),
+            _ => return Err(ConfigError::UnknownRuleType(kind)),
+        };
+        Ok(match self.mode {
+            Some(Mode::Error) => checker.into_error(),
+            Some(Mode::Attention) | None => checker.into_attention(),
+        })
+    }
+}
+
+pub fn from_specs(specs: HashMap<String, Vec<RuleSpec>>) -> Result<impl CheckList, ConfigError> {
+    let mut lists = Vec::new();
+    for (key, rules) in specs {
+        for rule in rules {
+            let checker = rule.into_checker(&key)?;
+            lists.push((key.to_string(), checker));
+        }
+    }
+    Ok(lists.into_checklist()?)
+}
+
+pub fn from_toml(input: &str) -> Result<impl CheckList, ConfigError> {
+    let rules: HashMap<String, Rules> =
+        toml::from_str(input).map_err(|e| ConfigError::Parse(e.to_string()))?;
+    from_specs(rules.into_iter().map(|(k, v)| (k, v.into())).collect())
+}
+
+pub fn from_yaml(input: &str) -> Result<impl CheckList, ConfigError> {
+    let rules: HashMap<String, Rules> =
+        serde_yaml::from_str(input).map_err(|e| ConfigError::Parse(e.to_string()))?;
+    from_specs(rules.into_iter().map(|(k, v)| (k, v.into())).collect())
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ConfigError {
+    Parse(String),
+    Invalid(String),
+    UnknownRuleType(String),
+    MissingField { rule: String, field: String },
+    Flatten(FlattenError),
+}
+
+impl From<FlattenError> for ConfigError {
+    fn from(value: FlattenError) -> Self {
+        ConfigError::Flatten(value)
+    }
+}
+
+impl Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Parse(msg) => write!(f, "Parse error: {}", msg),
+            ConfigError::Invalid(msg) => write!(f, "Invalid rule: {}", msg),
+            ConfigError::UnknownRuleType(kind) => write!(f, "Unknown rule type `{}`", kind),
+            ConfigError::MissingField { rule, field } => {
+                write!(f, "Rule `{}` is missing field `{}`", rule, field)
+            }
+            ConfigError::Flatten(e) => write!(f, "{}", e),
+        }
+    }
+}
+
+impl Error for ConfigError {}
+
+#[cfg(test)]
+mod tests {
+    use super::{from_specs, from_toml, from_yaml, ConfigError, Mode};
+    use crate::{
+        check::{Checkers, Notice, SwitchMode},
+        commit::{CheckList, IntoCheckList},
+        value::KindSet,
+    };
+
+    #[test]
+    fn test_from_toml() {
+        let input = r#"
+            [age]
+            type = "between"
+            min = 0
+            max = 150
+            message = "out of range"
+            mode = "error"
+
+            [country]
+            type = "exact"
+            value = "JP"
+            message = "unsupported country"
+        "#;
+        let map = from_toml(input).unwrap();
+        assert_eq!(
+            map.items_sorted(),
+            vec![
+                ("age".to_string(), KindSet::NUMBER),
+                ("country".to_string(), KindSet::NUMBER | KindSet::LITERAL),
+            ]
+        );
+        assert_eq!(map.commit_strict("age", 20.into()), Ok(Some(Notice::Clear)));
+        assert_eq!(
+            map.commit_strict("age", 200.into()),
+            Err("out of range".to_string())
+        );
+        assert_eq!(
+            map.commit_strict("country", "US".into()),
+            Ok(Some(Notice::Attention("unsupported country".to_string())))
+        );
+    }
+
+    #[test]
+    fn test_from_toml_invalid() {
+        assert!(matches!(
+            from_toml("[age\ntype = 1").err(),
+            Some(ConfigError::Parse(_))
+        ));
+        assert!(matches!(
+            from_toml("[age]\ntype = \"regex\"\npattern = \"(\"\nmessage = \"x\"").err(),
+            Some(ConfigError::Invalid(_))
+        ));
+    }
+
+    #[test]
+    fn test_missing_type() {
+        assert_eq!(
+            from_toml("[age]\nmessage = \"x\"").err(),
+            Some(ConfigError::MissingField {
+                rule: "age".to_string(),
+                field: "type".to_string(),
+            })
+        );
+        assert_eq!(
+            from_yaml("age:\n  - message: x\n").err(),
+            Some(ConfigError::MissingField {
+                rule: "age".to_string(),
+                field: "type".to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_unknown_field() {
+        let err = from_toml("[name]\ntype = \"not_empty\"\nmesage = \"required\"").err();
+        assert!(
+            matches!(&err, Some(ConfigError::Parse(msg)) if msg.contains("unknown field `mesage`"))
+        );
+        let err = from_yaml("name:\n  - type: not_empty\n    mesage: required\n").err();
+        assert!(
+            matches!(&err, Some(ConfigError::Parse(msg)) if msg.contains("unknown field `mesage`"))
+        );
+    }
+
+    #[test]
+    fn test_from_yaml() {
+        let yaml = r#"
+score:
+  - type: between
+    min: -2
+    max: 2
+    message: caution
+  - type: between
+    min: -5
+    max: 5
+    message: error
+    mode: error
+status:
+  type: one_of
+  values: [open, closed]
+  message: unknown status
+"#;
+        let toml = r#"
+            [[score]]
+            type = "between"
+            min = -2
+            max = 2
+            message = "caution"
+
+            [[score]]
+            type = "between"
+            min = -5
+            max = 5
+            message = "error"
+            mode = "error"
+
+            [status]
+            type = "one_of"
+            values = ["open", "closed"]
+            message = "unknown status"
+        "#;
+        let map = from_yaml(yaml).unwrap();
+        assert_eq!(
+            map.commit_strict("score", 0.into()),
+            Ok(Some(Notice::Clear))
+        );
+        assert_eq!(
+            map.commit_strict("score", 3.into()),
+            Ok(Some(Notice::Attention("caution".to_string())))
+        );
+        assert_eq!(
+            map.commit_strict("score", 6.into()),
+            Err("error".to_string())
+        );
+        assert_eq!(
+            map.commit_strict("status", "pending".into()),
+            Ok(Some(Notice::Attention("unknown status".to_string())))
+        );
+
+        let from_toml = from_toml(toml).unwrap();
+        assert_eq!(map.items_sorted(), from_toml.items_sorted());
+        assert_eq!(map.rules(), from_toml.rules());
+    }
+
+    #[test]
+    fn test_unknown_rule_type() {
+        let input = r#"
+            [age]
+            type = "roughly"
+            message = "x"
+        "#;
+        assert_eq!(
+            from_toml(input).err(),
+            Some(ConfigError::UnknownRuleType("roughly".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_missing_field() {
+        let input = r#"
+            [age]
+            type = "between"
+            min = 0
+            message = "out of range"
+        "#;
+        let err = from_toml(input).err().unwrap();
+        assert_eq!(
+            err,
+            ConfigError::MissingField {
+                rule: "age".to_string(),
+                field: "max".to_string(),
+            }
+        );
+        assert_eq!(err.to_string(), "Rule `age` is missing field `max`");
+    }
+
+    #[test]
+    fn test_snapshot() {
+        let list = vec![
+            (
+                "age".to_string(),
+                Checkers::Between(0.0, 150.0, "out of range".to_string()).into_error(),
+            ),
+            (
+                "age".to_string(),
+                Checkers::IsInteger("not an integer".to_string()).into_attention(),
+            ),
+            (
+                "id".to_string(),
+                Checkers::regex_str("^[a-z]+$", "bad id")
+                    .unwrap()
+                    .into_attention(),
+            ),
+        ]
+        .into_checklist()
+        .unwrap();
+        let snapshot = list.snapshot().unwrap();
+        assert_eq!(snapshot["age"][0].mode, Some(Mode::Error));
+        assert_eq!(snapshot["id"][0].pattern.as_deref(), Some("^[a-z]+$"));
+
+        let mut tweaked = snapshot.clone();
+        tweaked.get_mut("age").unwrap()[0].max = Some(120.0);
+        let reloaded = from_specs(snapshot.clone()).unwrap();
+        let variant = from_specs(tweaked).unwrap();
+        assert_eq!(reloaded.snapshot(), Some(snapshot));
+        assert_eq!(reloaded.items(), list.items());
+        assert_eq!(reloaded.rules(), list.rules());
+        for (key, value) in [
+            ("age", 130.into()),
+            ("age", 1.5.into()),
+            ("id", "A1".into()),
+        ] {
+            assert_eq!(
+                reloaded.commit_ref(key, &value),
+                list.commit_ref(key, &value)
+            );
+        }
+        assert_eq!(
+            variant.commit_strict("age", 130.into()),
+            Err("out of range".to_string())
+        );
+
+        let custom = vec![(
+            "id".to_string(),
+            Checkers::Email("invalid".to_string()).into_attention(),
+        )]
+        .into_checklist()
+        .unwrap();
+        assert_eq!(custom.snapshot(), None);
+        assert_eq!(list.with_default(Checkers::Any).snapshot(), None);
+    }
+}
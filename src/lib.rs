@@ -1,3 +1,8 @@
 pub mod check;
+#[cfg(feature = "async")]
+pub mod check_async;
 pub mod commit;
+#[cfg(feature = "config")]
+pub mod config;
+mod iso;
 pub mod value;
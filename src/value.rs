@@ -1,19 +1,163 @@
-#[derive(Debug, Clone, PartialEq, Eq)]
+use std::{
+    cmp::Ordering,
+    fmt::Display,
+    ops::{BitAnd, BitOr},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Value {
     inner: String,
     kind: ValueKind,
 }
 
 impl Value {
+    pub fn new(inner: impl Into<String>, kind: ValueKind) -> Value {
+        Value {
+            inner: inner.into(),
+            kind,
+        }
+    }
+
+    pub fn number(inner: impl Into<String>) -> Value {
+        Value::new(inner, ValueKind::Number)
+    }
+
+    pub fn literal(inner: impl Into<String>) -> Value {
+        Value::new(inner, ValueKind::Literal)
+    }
+
+    pub fn null() -> Value {
+        Value::new("", ValueKind::Null)
+    }
+
     pub fn is_kind_of(&self, kind: ValueKind) -> bool {
         self.kind == kind
     }
+
+    pub fn kind(&self) -> ValueKind {
+        self.kind
+    }
+
+    /// `None` unless the value is a number that parses as `f64`.
+    pub fn as_f64(&self) -> Option<f64> {
+        match self.kind {
+            ValueKind::Number => f64::try_from(self).ok(),
+            _ => None,
+        }
+    }
+
+    pub fn compare_numeric(&self, other: &Value) -> Option<Ordering> {
+        self.as_f64()?.partial_cmp(&other.as_f64()?)
+    }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
 pub enum ValueKind {
     Number,
     Literal,
+    Boolean,
+    DateTime,
+    Null,
+}
+
+impl ValueKind {
+    const ALL: [ValueKind; 5] = [
+        ValueKind::Number,
+        ValueKind::Literal,
+        ValueKind::Boolean,
+        ValueKind::DateTime,
+        ValueKind::Null,
+    ];
+
+    const fn bit(self) -> u8 {
+        1 << self as u8
+    }
+}
+
+/// A set of accepted kinds, stored as bit flags so it is cheap to copy and compare.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "Vec<ValueKind>", from = "Vec<ValueKind>")
+)]
+pub struct KindSet(u8);
+
+impl KindSet {
+    pub const EMPTY: KindSet = KindSet(0);
+    pub const NUMBER: KindSet = KindSet(ValueKind::Number.bit());
+    pub const LITERAL: KindSet = KindSet(ValueKind::Literal.bit());
+    pub const BOOLEAN: KindSet = KindSet(ValueKind::Boolean.bit());
+    pub const DATE_TIME: KindSet = KindSet(ValueKind::DateTime.bit());
+    pub const NULL: KindSet = KindSet(ValueKind::Null.bit());
+
+    pub fn contains(&self, kind: ValueKind) -> bool {
+        self.0 & kind.bit() != 0
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    pub fn union(self, other: KindSet) -> KindSet {
+        KindSet(self.0 | other.0)
+    }
+
+    pub fn intersection(self, other: KindSet) -> KindSet {
+        KindSet(self.0 & other.0)
+    }
+
+    /// Kinds in declaration order.
+    pub fn to_vec(&self) -> Vec<ValueKind> {
+        ValueKind::ALL
+            .into_iter()
+            .filter(|kind| self.contains(*kind))
+            .collect()
+    }
+}
+
+impl BitOr for KindSet {
+    type Output = KindSet;
+
+    fn bitor(self, rhs: KindSet) -> KindSet {
+        self.union(rhs)
+    }
+}
+
+impl BitAnd for KindSet {
+    type Output = KindSet;
+
+    fn bitand(self, rhs: KindSet) -> KindSet {
+        self.intersection(rhs)
+    }
+}
+
+impl From<ValueKind> for KindSet {
+    fn from(value: ValueKind) -> Self {
+        KindSet(value.bit())
+    }
+}
+
+impl FromIterator<ValueKind> for KindSet {
+    fn from_iter<I: IntoIterator<Item = ValueKind>>(iter: I) -> Self {
+        iter.into_iter()
+            .fold(KindSet::EMPTY, |set, kind| set | kind.into())
+    }
+}
+
+impl From<Vec<ValueKind>> for KindSet {
+    fn from(value: Vec<ValueKind>) -> Self {
+        value.into_iter().collect()
+    }
+}
+
+impl From<KindSet> for Vec<ValueKind> {
+    fn from(value: KindSet) -> Self {
+        value.to_vec()
+    }
 }
 
 impl From<u32> for Value {
@@ -43,6 +187,52 @@ impl From<f32> for Value {
     }
 }
 
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value {
+            inner: value.to_string(),
+            kind: ValueKind::Number,
+        }
+    }
+}
+
+impl From<u64> for Value {
+    fn from(value: u64) -> Self {
+        Value {
+            inner: value.to_string(),
+            kind: ValueKind::Number,
+        }
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value {
+            inner: value.to_string(),
+            kind: ValueKind::Number,
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value {
+            inner: value.to_string(),
+            kind: ValueKind::Boolean,
+        }
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::NaiveDate> for Value {
+    fn from(value: chrono::NaiveDate) -> Self {
+        Value {
+            inner: value.to_string(),
+            kind: ValueKind::DateTime,
+        }
+    }
+}
+
 impl From<&str> for Value {
     fn from(value: &str) -> Self {
         Value {
@@ -52,6 +242,24 @@ impl From<&str> for Value {
     }
 }
 
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value {
+            inner: value,
+            kind: ValueKind::Literal,
+        }
+    }
+}
+
+impl From<&String> for Value {
+    fn from(value: &String) -> Self {
+        Value {
+            inner: value.to_owned(),
+            kind: ValueKind::Literal,
+        }
+    }
+}
+
 impl TryFrom<Value> for f64 {
     type Error = String;
 
@@ -68,6 +276,66 @@ impl TryFrom<&Value> for f64 {
     }
 }
 
+impl TryFrom<Value> for i64 {
+    type Error = String;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        value.inner.parse::<i64>().map_err(|e| e.to_string())
+    }
+}
+
+impl TryFrom<&Value> for i64 {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        value.inner.parse::<i64>().map_err(|e| e.to_string())
+    }
+}
+
+impl TryFrom<Value> for u64 {
+    type Error = String;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        value.inner.parse::<u64>().map_err(|e| e.to_string())
+    }
+}
+
+impl TryFrom<&Value> for u64 {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        value.inner.parse::<u64>().map_err(|e| e.to_string())
+    }
+}
+
+impl TryFrom<Value> for bool {
+    type Error = String;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        value.inner.parse::<bool>().map_err(|e| e.to_string())
+    }
+}
+
+impl TryFrom<&Value> for bool {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        value.inner.parse::<bool>().map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<&Value> for chrono::NaiveDate {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        value
+            .inner
+            .parse::<chrono::NaiveDate>()
+            .map_err(|e| e.to_string())
+    }
+}
+
 impl From<Value> for String {
     fn from(value: Value) -> Self {
         value.inner
@@ -80,8 +348,150 @@ impl From<&Value> for String {
     }
 }
 
-impl ToString for Value {
-    fn to_string(&self) -> String {
-        self.inner.clone()
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.inner)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cmp::Ordering, collections::HashSet};
+
+    use super::{KindSet, Value, ValueKind};
+    use crate::check::{CheckError, Checker, Checkers, Notice};
+
+    #[test]
+    fn test_bool() {
+        let value: Value = true.into();
+        assert!(value.is_kind_of(ValueKind::Boolean));
+        assert_eq!(value.to_string(), "true");
+        assert_eq!(bool::try_from(&value), Ok(true));
+        assert_eq!(bool::try_from(Value::from(false)), Ok(false));
+        assert!(bool::try_from(Value::from("yes")).is_err());
+    }
+
+    #[test]
+    fn test_f64() {
+        for v in [-1.5, -0.1, 0.1 + 0.2, f64::MIN_POSITIVE, -1e300] {
+            let value: Value = v.into();
+            assert!(value.is_kind_of(ValueKind::Number));
+            assert_eq!(f64::try_from(&value), Ok(v));
+        }
+    }
+
+    #[test]
+    fn test_i64() {
+        let value: Value = i64::MAX.into();
+        assert!(value.is_kind_of(ValueKind::Number));
+        assert_eq!(value.to_string(), "9223372036854775807");
+
+        let value: Value = i64::MIN.into();
+        assert_eq!(value.to_string(), "-9223372036854775808");
+    }
+
+    #[test]
+    fn test_malformed_number() {
+        let value = Value {
+            inner: "twelve".to_string(),
+            kind: ValueKind::Number,
+        };
+        let checker = Checkers::Between(0.0, 100.0, "out of range".to_string());
+        assert!(matches!(checker.check(&value), Err(CheckError::Parse(_))));
+    }
+
+    #[test]
+    fn test_string() {
+        let owned = "abc".to_string();
+        let borrowed: Value = (&owned).into();
+        let value: Value = owned.into();
+        assert!(value.is_kind_of(ValueKind::Literal));
+        assert_eq!(value, borrowed);
+        assert_eq!(value, "abc".into());
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_malformed_date() {
+        use chrono::NaiveDate;
+
+        let value = Value {
+            inner: "2024-13-45".to_string(),
+            kind: ValueKind::DateTime,
+        };
+        let checker = Checkers::DateBetween(
+            NaiveDate::from_ymd_opt(2024, 1, 1).unwrap(),
+            NaiveDate::from_ymd_opt(2024, 12, 31).unwrap(),
+            "out of range".to_string(),
+        );
+        assert!(matches!(checker.check(&value), Err(CheckError::Parse(_))));
+    }
+
+    #[test]
+    fn test_constructors() {
+        let value = Value::number("42");
+        assert!(value.is_kind_of(ValueKind::Number));
+        let checker = Checkers::Between(0.0, 100.0, "out of range".to_string());
+        assert_eq!(checker.check(&value), Ok(Notice::Clear));
+        assert_eq!(
+            checker.check(&Value::number("142")),
+            Ok(Notice::Attention("out of range".to_string()))
+        );
+
+        assert_eq!(Value::literal("42"), "42".into());
+        assert_eq!(Value::new("true", ValueKind::Boolean), true.into());
+        assert!(Value::null().is_kind_of(ValueKind::Null));
+    }
+
+    #[test]
+    fn test_kind() {
+        assert_eq!(Value::from(42).kind(), ValueKind::Number);
+        assert_eq!(Value::from("42").kind(), ValueKind::Literal);
+    }
+
+    #[test]
+    fn test_compare_numeric() {
+        let start = Value::from(3);
+        let end = Value::from(10.5);
+        assert_eq!(start.as_f64(), Some(3.0));
+        assert_eq!(start.compare_numeric(&end), Some(Ordering::Less));
+        assert_eq!(end.compare_numeric(&start), Some(Ordering::Greater));
+        assert_eq!(start.compare_numeric(&3.into()), Some(Ordering::Equal));
+
+        let literal = Value::from("3");
+        assert_eq!(literal.as_f64(), None);
+        assert_eq!(start.compare_numeric(&literal), None);
+    }
+
+    #[test]
+    fn test_kind_set() {
+        let set = KindSet::NUMBER | KindSet::LITERAL;
+        assert_eq!(set, KindSet::LITERAL | KindSet::NUMBER);
+        assert_eq!(set, vec![ValueKind::Literal, ValueKind::Number].into());
+        assert_ne!(set, KindSet::NUMBER);
+        assert!(set.contains(ValueKind::Number));
+        assert!(!set.contains(ValueKind::Boolean));
+        assert_eq!(set & KindSet::NUMBER, KindSet::NUMBER);
+        assert!((set & KindSet::BOOLEAN).is_empty());
+        assert_eq!(set.to_vec(), vec![ValueKind::Number, ValueKind::Literal]);
+    }
+
+    #[test]
+    fn test_hash() {
+        let mut set = HashSet::new();
+        assert!(set.insert(Value::from(42)));
+        assert!(set.insert(Value::from("42")));
+        assert!(!set.insert(Value::number("42")));
+        assert_eq!(set.len(), 2);
+    }
+
+    #[test]
+    fn test_integers() {
+        let value = Value::from(9007199254740993_u64);
+        assert_eq!(i64::try_from(&value), Ok(9007199254740993));
+        assert_eq!(u64::try_from(value.clone()), Ok(9007199254740993));
+        assert_eq!(f64::try_from(&value), Ok(9007199254740992.0));
+        assert!(i64::try_from(Value::from(1.5)).is_err());
+        assert!(u64::try_from(Value::from(-1)).is_err());
     }
 }